@@ -0,0 +1,81 @@
+//! # Example: Abstention Detection and Fallback Routing
+//!
+//! A bot should say "I don't know, contacting a human" instead of guessing —
+//! but detecting refusals from free text is unreliable. This example
+//! demonstrates the abstention mechanism: an optional post-answer check
+//! combines heuristics (hedging phrases, empty retrieval in RAG mode, low
+//! logprob confidence when available) with an optional LLM self-assessment,
+//! setting an `Abstained` flag and reason on the run outcome. When flagged,
+//! a configurable fallback action runs: a canned message, escalation to
+//! another model, or a webhook for human takeover.
+
+use helios_engine::agent::{AbstentionPolicy, FallbackAction};
+use helios_engine::{Agent, Config, RAGTool};
+
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - Abstention & Fallback Example");
+    println!("================================================\n");
+
+    let config = Config::from_file("config.toml")?;
+
+    let rag_tool = RAGTool::new_in_memory(
+        "https://api.openai.com/v1/embeddings",
+        std::env::var("OPENAI_API_KEY").unwrap_or_default(),
+    );
+
+    // Hedging heuristics plus self-assessment; on abstention, return a
+    // canned message and fire a webhook so a human can take over.
+    let policy = AbstentionPolicy::default()
+        .hedging_threshold(0.6)
+        .abstain_on_empty_retrieval(true)
+        .llm_self_assessment(true)
+        .fallback(FallbackAction::canned(
+            "I'm not confident about this one — I've asked a colleague to follow up.",
+        ))
+        .fallback(FallbackAction::webhook(
+            "https://support.example.com/hooks/human-takeover",
+        ));
+
+    let mut agent = Agent::builder("SupportBot")
+        .config(config)
+        .system_prompt("Answer customer questions from the knowledge base only.")
+        .tool(Box::new(rag_tool))
+        .abstention(policy)
+        .build()
+        .await?;
+
+    // Seed a tiny knowledge base.
+    agent
+        .chat("Store this: 'Refunds are processed within 5 business days.'")
+        .await?;
+
+    // --- Example 1: A confident, grounded answer passes through ---
+    println!("Example 1: Confident Answer");
+    println!("===========================\n");
+
+    let response = agent.chat("How long do refunds take?").await?;
+    println!("Agent: {}", response);
+    println!("abstained: {}\n", agent.last_run_outcome().abstained());
+
+    // --- Example 2: Empty retrieval triggers the fallback ---
+    println!("Example 2: Unknown Topic");
+    println!("========================\n");
+
+    let response = agent
+        .chat("What is your policy on cryptocurrency payments?")
+        .await?;
+    println!("Agent: {}", response);
+
+    let outcome = agent.last_run_outcome();
+    if outcome.abstained() {
+        println!("abstained: true");
+        println!("reason:    {}", outcome.abstention_reason().unwrap_or_default());
+        println!("fallback:  canned message + human-takeover webhook fired");
+    }
+
+    // Abstention rate per deployment is tracked in metrics:
+    //   helios_abstentions_total{deployment="support"}
+
+    Ok(())
+}
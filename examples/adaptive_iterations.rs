@@ -0,0 +1,78 @@
+//! # Example: Adaptive Iteration Budget
+//!
+//! Fixed `max_iterations` is either wastefully high for simple questions or
+//! too low for complex tool chains. This example demonstrates the adaptive
+//! mode: the agent starts with a base iteration budget and extends it (up to
+//! a hard cap) while progress signals are positive — each iteration produced
+//! a successful, novel tool call or new information — and stops early when
+//! the loop stagnates (repeated identical tool calls, shrinking response
+//! deltas, no tool calls and no finish condition).
+//!
+//! The run outcome records every budget decision so you can see why the loop
+//! ended when it did.
+
+use helios_engine::agent::{AdaptiveIterations, StagnationPolicy};
+use helios_engine::{Agent, CalculatorTool, Config, FileReadTool, FileSearchTool};
+
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - Adaptive Iterations Example");
+    println!("==============================================\n");
+
+    let config = Config::from_file("config.toml")?;
+
+    // Start with a small budget, extend by 3 iterations whenever the agent is
+    // making progress, and never exceed 20 iterations total. The stagnation
+    // detector stops the loop after 2 consecutive unproductive iterations.
+    let adaptive = AdaptiveIterations::new(5)
+        .extend_by(3)
+        .hard_cap(20)
+        .stagnation(StagnationPolicy::default().max_unproductive(2));
+
+    let mut agent = Agent::builder("ResearchAgent")
+        .config(config)
+        .system_prompt(
+            "You are a research assistant. Use your tools to gather \
+             information before answering.",
+        )
+        .tool(Box::new(CalculatorTool))
+        .tool(Box::new(FileSearchTool))
+        .tool(Box::new(FileReadTool))
+        .adaptive_iterations(adaptive)
+        .build()
+        .await?;
+
+    // --- Example 1: A simple question finishes well under the base budget ---
+    println!("Example 1: Simple Question");
+    println!("==========================\n");
+
+    let response = agent.chat("What is 17 * 23?").await?;
+    println!("Agent: {}\n", response);
+
+    // --- Example 2: A complex tool chain earns budget extensions ---
+    println!("Example 2: Complex Tool Chain");
+    println!("=============================\n");
+
+    let response = agent
+        .chat(
+            "Find every Rust file in src/, read the two largest ones, and \
+             summarize what they do.",
+        )
+        .await?;
+    println!("Agent: {}\n", response);
+
+    // --- Inspect the budget decisions from the last run ---
+    println!("Budget Decisions");
+    println!("================\n");
+
+    let outcome = agent.last_run_outcome();
+    println!("Iterations used: {}", outcome.iterations_used);
+    for decision in outcome.budget_decisions() {
+        println!(
+            "  iteration {}: {} ({})",
+            decision.iteration, decision.action, decision.reason
+        );
+    }
+
+    Ok(())
+}
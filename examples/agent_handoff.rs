@@ -0,0 +1,75 @@
+//! # Example: Agent Handoff
+//!
+//! Outside of forests, two standalone agents can hand a conversation off
+//! cleanly — think customer support routing from triage to billing. This
+//! example demonstrates `Agent::handoff_to`: it produces a structured,
+//! LLM-generated handoff summary from the session (plus selected memory keys
+//! and pinned messages), seeds the target agent's session with it, records
+//! the handoff in both run outcomes, and optionally returns a user-facing
+//! transition message. The whole transcript is *not* transferred — only the
+//! summary carries over.
+//!
+//! The `HandoffTool` variant lets the model itself trigger the handoff; the
+//! host sees it on the run outcome and switches which agent handles
+//! subsequent turns.
+
+use helios_engine::agent::HandoffOptions;
+use helios_engine::{Agent, Config, HandoffTool};
+
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - Agent Handoff Example");
+    println!("========================================\n");
+
+    let config = Config::from_file("config.toml")?;
+
+    let mut triage = Agent::builder("Triage")
+        .config(config.clone())
+        .system_prompt(
+            "You are first-line support. Gather the customer's issue. If it \
+             is about invoices or payments, hand off to billing.",
+        )
+        .tool(Box::new(HandoffTool::to("Billing")))
+        .build()
+        .await?;
+
+    let mut billing = Agent::builder("Billing")
+        .config(config)
+        .system_prompt("You are a billing specialist. Resolve invoice and payment issues.")
+        .build()
+        .await?;
+
+    // --- The customer talks to triage first ---
+    println!("Customer: I was double-charged on invoice #4821 last week.\n");
+    let response = triage
+        .chat("I was double-charged on invoice #4821 last week.")
+        .await?;
+    println!("Triage: {}\n", response);
+
+    // --- Explicit handoff from the host ---
+    println!("Performing handoff to billing...\n");
+
+    let options = HandoffOptions::default()
+        .include_memory_keys(&["customer_id", "invoice_number"])
+        .transition_message(true);
+
+    let handoff = triage.handoff_to(&mut billing, options).await?;
+    println!("Handoff summary:\n{}\n", handoff.summary);
+    if let Some(msg) = handoff.transition_message {
+        println!("To customer: {}\n", msg);
+    }
+
+    // --- Billing answers a follow-up using only the carried summary ---
+    let response = billing
+        .chat("So which invoice was affected, and what happens next?")
+        .await?;
+    println!("Billing: {}\n", response);
+
+    // If the model triggered the handoff itself via HandoffTool, the host
+    // finds it on the outcome and routes the next turn accordingly:
+    if let Some(target) = triage.last_run_outcome().requested_handoff() {
+        println!("(model requested a handoff to: {})", target);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,86 @@
+//! # Example: Automatic RAG Context Injection
+//!
+//! Tool-driven RAG depends on the model remembering to call the search
+//! tool — which it often forgets. This example demonstrates the automatic
+//! retrieval-augmentation mode: before each LLM call, the agent embeds the
+//! user message, runs `RAGSystem::search`, and injects the top-k chunks into
+//! a system-level context block under a token budget. It coexists with the
+//! explicit `RAGTool` and can be skipped per message.
+//!
+//! ## Prerequisites
+//!
+//! ```sh
+//! export OPENAI_API_KEY=your-key
+//! ```
+
+use helios_engine::chat::ChatOptions;
+use helios_engine::rag::AutoRagConfig;
+use helios_engine::{Agent, Config, Document, InMemoryVectorStore, OpenAIEmbeddings, RAGSystem};
+
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - Auto RAG Example");
+    println!("===================================\n");
+
+    let embeddings = OpenAIEmbeddings::new(
+        "https://api.openai.com/v1/embeddings".to_string(),
+        std::env::var("OPENAI_API_KEY").unwrap_or_default(),
+    );
+
+    let vector_store = InMemoryVectorStore::new(embeddings);
+    let mut rag_system = RAGSystem::new(vector_store);
+
+    rag_system
+        .add_documents(vec![
+            Document {
+                id: "vacation".to_string(),
+                content: "Employees accrue 2 vacation days per month, capped at 30.".to_string(),
+                metadata: std::collections::HashMap::new(),
+            },
+            Document {
+                id: "remote".to_string(),
+                content: "Remote work is allowed up to 3 days per week with manager approval.".to_string(),
+                metadata: std::collections::HashMap::new(),
+            },
+        ])
+        .await?;
+
+    let config = Config::from_file("config.toml")?;
+
+    // Every turn automatically retrieves the 3 best chunks above 0.7
+    // similarity, capped at 1200 context tokens.
+    let mut agent = Agent::builder("HrBot")
+        .config(config)
+        .system_prompt("You answer HR policy questions.")
+        .auto_rag(
+            rag_system,
+            AutoRagConfig {
+                top_k: 3,
+                min_score: 0.7,
+                max_context_tokens: 1200,
+            },
+        )
+        .build()
+        .await?;
+
+    // --- Example 1: No tool call needed — context arrives automatically ---
+    println!("Example 1: Automatic Injection");
+    println!("==============================\n");
+
+    let response = agent.chat("How many vacation days do I get per month?").await?;
+    println!("Agent: {}\n", response);
+
+    let response = agent.chat("Can I work from home on Mondays and Fridays?").await?;
+    println!("Agent: {}\n", response);
+
+    // --- Example 2: Skip retrieval for chit-chat ---
+    println!("Example 2: Per-Message Skip");
+    println!("===========================\n");
+
+    let response = agent
+        .chat_with_options("Thanks, that's all!", ChatOptions::default().skip_auto_rag())
+        .await?;
+    println!("Agent: {}", response);
+
+    Ok(())
+}
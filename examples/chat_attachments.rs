@@ -0,0 +1,79 @@
+//! # Example: Inline Attachments in User Messages
+//!
+//! Users paste a CSV or upload a file and ask questions about it — no
+//! manual RAG pre-ingestion needed. This example demonstrates attachments
+//! on `Agent::chat_with`: each attachment (name, mime, bytes or path) is
+//! registered for the turn. Small text attachments are inlined under a
+//! delimited block with size caps; larger ones are exposed through
+//! auto-registered ephemeral tools — `read_attachment(name, offset, length)`
+//! and `search_attachment(name, query)` backed by a temporary in-memory RAG
+//! index — which are removed when the turn's attachments expire.
+//!
+//! The serve chat endpoint accepts the same attachments via the standard
+//! content-parts format or multipart upload.
+
+use helios_engine::chat::Attachment;
+use helios_engine::{Agent, Config};
+
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - Chat Attachments Example");
+    println!("===========================================\n");
+
+    let config = Config::from_file("config.toml")?;
+
+    let mut agent = Agent::builder("FileQA")
+        .config(config)
+        .system_prompt(
+            "Answer questions about attached files. Use read_attachment and \
+             search_attachment for large attachments.",
+        )
+        .build()
+        .await?;
+
+    // --- Example 1: A small CSV is inlined directly ---
+    println!("Example 1: Small Inline Attachment");
+    println!("==================================\n");
+
+    let csv = "name,region,revenue\n\
+               Alpha,EU,120000\n\
+               Beta,US,98000\n\
+               Gamma,APAC,143000\n";
+
+    let response = agent
+        .chat_with(
+            "Which region has the highest revenue in this file?",
+            vec![Attachment::from_bytes("sales.csv", "text/csv", csv.as_bytes())],
+        )
+        .await?;
+    println!("Agent: {}\n", response);
+
+    // --- Example 2: A large file gets ephemeral tools ---
+    println!("Example 2: Large Attachment via Ephemeral Tools");
+    println!("===============================================\n");
+
+    // Above the inline size cap, the attachment is indexed into a temporary
+    // in-memory RAG store and the model gets read/search tools for it.
+    let response = agent
+        .chat_with(
+            "Does this log file contain any out-of-memory errors? Quote the line.",
+            vec![Attachment::from_path("server.log")?],
+        )
+        .await?;
+    println!("Agent: {}\n", response);
+
+    // --- Example 3: Attachments expire with the turn ---
+    println!("Example 3: Expiry");
+    println!("=================\n");
+
+    // The ephemeral tools are gone now; a follow-up turn without the
+    // attachment can't read it.
+    let response = agent.chat("Read sales.csv again.").await?;
+    println!("Agent: {}\n", response);
+
+    println!("Over HTTP, send attachments as content parts:");
+    println!("  {{\"type\": \"file\", \"name\": \"sales.csv\",");
+    println!("   \"mime\": \"text/csv\", \"data\": \"<base64>\"}}");
+
+    Ok(())
+}
@@ -0,0 +1,75 @@
+//! # Example: Cited Answers from Retrieved Context
+//!
+//! When an agent answers from RAG results there is no traceability. This
+//! example demonstrates `Agent::chat_with_citations`: retrieved chunks are
+//! presented to the model as numbered references, the model is instructed to
+//! cite `[1]`, `[2]`, and the call returns a `CitedResponse` whose citations
+//! are cross-referenced against the chunks actually injected that turn.
+//! Citations pointing at chunks that were never retrieved are flagged.
+//!
+//! ## Prerequisites
+//!
+//! ```sh
+//! export OPENAI_API_KEY=your-key
+//! ```
+
+use helios_engine::{Agent, Config, RAGTool};
+
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - Cited Answers Example");
+    println!("========================================\n");
+
+    let config = Config::from_file("config.toml")?;
+
+    let rag_tool = RAGTool::new_in_memory(
+        "https://api.openai.com/v1/embeddings",
+        std::env::var("OPENAI_API_KEY").unwrap_or_default(),
+    )
+    // Return results with stable ids and source metadata formatted as
+    // numbered references the model can cite.
+    .with_numbered_references(true);
+
+    let mut agent = Agent::builder("CitedAgent")
+        .config(config)
+        .system_prompt("Answer from the knowledge base and cite your sources.")
+        .tool(Box::new(rag_tool))
+        .build()
+        .await?;
+
+    // Seed the knowledge base.
+    for fact in [
+        "The support SLA promises first response within 4 business hours.",
+        "Enterprise customers get a dedicated account manager.",
+        "The free tier is limited to 1000 API calls per month.",
+    ] {
+        agent.chat(&format!("Store this: '{}'", fact)).await?;
+    }
+    println!("✓ Knowledge base seeded\n");
+
+    // --- Ask with citations ---
+    let cited = agent
+        .chat_with_citations("What do enterprise customers get, and how fast is support?")
+        .await?;
+
+    println!("Answer");
+    println!("======\n");
+    println!("{}\n", cited.content);
+
+    println!("Citations");
+    println!("=========\n");
+    for citation in &cited.citations {
+        println!(
+            "[{}] {} (score {:.3})\n    \"{}\"",
+            citation.id, citation.source, citation.score, citation.snippet
+        );
+    }
+
+    // Hallucinated references — a [4] when only three chunks were injected —
+    // are caught by the cross-reference step.
+    if !cited.unresolved_citations.is_empty() {
+        println!("\n⚠ citations not backed by retrieved chunks: {:?}", cited.unresolved_citations);
+    }
+
+    Ok(())
+}
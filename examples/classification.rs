@@ -0,0 +1,87 @@
+//! # Example: Vocabulary-Constrained Classification
+//!
+//! Using agents for classification ("bug", "feature", "question") by parsing
+//! free-text answers is brittle. This example demonstrates
+//! `Agent::classify`, which constrains the output to one of the supplied
+//! labels using the best mechanism the provider offers — logit_bias and
+//! logprobs for OpenAI-compatible APIs, a GBNF grammar for local models, a
+//! strict JSON enum schema otherwise — and returns the chosen label plus a
+//! confidence estimate and the raw evidence.
+//!
+//! A multi-label variant with a threshold is also shown. Misconfigured
+//! label sets (duplicates, empty) error at call time.
+
+use helios_engine::agent::ClassifyOptions;
+use helios_engine::{Agent, Config};
+
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - Classification Example");
+    println!("=========================================\n");
+
+    let config = Config::from_file("config.toml")?;
+
+    let mut agent = Agent::builder("Triage")
+        .config(config)
+        .system_prompt("You triage incoming issue reports.")
+        .build()
+        .await?;
+
+    let labels = ["bug", "feature", "question"];
+
+    // --- Example 1: Single-label classification ---
+    println!("Example 1: Single Label");
+    println!("=======================\n");
+
+    let inputs = [
+        "The app crashes when I click save twice quickly.",
+        "It would be great if exports supported CSV.",
+        "How do I change my password?",
+    ];
+
+    for input in inputs {
+        let result = agent
+            .classify(input, &labels, ClassifyOptions::default())
+            .await?;
+        println!(
+            "{:<55} → {} (confidence {:.2})",
+            input, result.label, result.confidence
+        );
+    }
+
+    // --- Example 2: Multi-label with a threshold ---
+    println!("\nExample 2: Multi-Label");
+    println!("======================\n");
+
+    let result = agent
+        .classify_multi(
+            "The export button is broken, and while you're at it, \
+             please add PDF export too.",
+            &labels,
+            ClassifyOptions::default().threshold(0.4),
+        )
+        .await?;
+
+    for scored in &result.labels {
+        println!("{} ({:.2})", scored.label, scored.score);
+    }
+
+    // --- Example 3: Bad label sets fail fast ---
+    println!("\nExample 3: Validation");
+    println!("=====================\n");
+
+    match agent
+        .classify("anything", &["bug", "bug"], ClassifyOptions::default())
+        .await
+    {
+        Err(e) => println!("duplicate labels rejected: {}", e),
+        Ok(_) => println!("unexpected success"),
+    }
+
+    match agent.classify("anything", &[], ClassifyOptions::default()).await {
+        Err(e) => println!("empty label set rejected: {}", e),
+        Ok(_) => println!("unexpected success"),
+    }
+
+    Ok(())
+}
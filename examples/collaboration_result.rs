@@ -0,0 +1,75 @@
+//! # Example: Structured Collaboration Results
+//!
+//! `execute_collaborative_task` returns a single `String`, losing all
+//! structure. This example demonstrates
+//! `execute_collaborative_task_detailed`, which returns a
+//! `CollaborationResult`: the final answer, the executed `TaskPlan`,
+//! per-task outcomes (agent, status, result, duration, token usage), the
+//! number of messages exchanged, and the total duration. The old method
+//! delegates to it, so existing code keeps working.
+
+use helios_engine::{Agent, Config, ForestBuilder};
+
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - Collaboration Result Example");
+    println!("===============================================\n");
+
+    let config = Config::from_file("config.toml")?;
+
+    let mut forest = ForestBuilder::new()
+        .config(config)
+        .agent(
+            "coordinator".to_string(),
+            Agent::builder("coordinator").system_prompt("You plan and delegate."),
+        )
+        .agent(
+            "researcher".to_string(),
+            Agent::builder("researcher").system_prompt("You research."),
+        )
+        .agent(
+            "writer".to_string(),
+            Agent::builder("writer").system_prompt("You write."),
+        )
+        .build()
+        .await?;
+
+    let result = forest
+        .execute_collaborative_task_detailed(
+            &"coordinator".to_string(),
+            "Create a short explainer on container orchestration.".to_string(),
+            vec!["researcher".to_string(), "writer".to_string()],
+        )
+        .await?;
+
+    // --- The final answer, as before ---
+    println!("Final Answer");
+    println!("============\n");
+    println!("{}\n", result.final_answer);
+
+    // --- Everything the string version threw away ---
+    println!("Run Details");
+    println!("===========\n");
+    println!("objective:          {}", result.plan.objective);
+    println!("tasks executed:     {}", result.task_results.len());
+    println!("messages exchanged: {}", result.messages_exchanged);
+    println!("total duration:     {:?}\n", result.total_duration);
+
+    println!("Per-Task Outcomes");
+    println!("=================\n");
+    for outcome in &result.task_results {
+        println!(
+            "{:<10} agent={:<12} {:<10} {:?}  {} tokens",
+            outcome.id, outcome.agent, outcome.status, outcome.duration, outcome.token_usage.total
+        );
+        println!("  result: {}", outcome.result);
+    }
+
+    // Structured results serialize cleanly for pipelines that persist or
+    // evaluate individual agents' contributions.
+    let json = serde_json::to_string_pretty(&result)?;
+    std::fs::write("collaboration_result.json", json)?;
+    println!("\n✓ Written to collaboration_result.json");
+
+    Ok(())
+}
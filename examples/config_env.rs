@@ -0,0 +1,70 @@
+//! # Example: Config from Environment Variables
+//!
+//! Committing API keys inside `config.toml` is a non-starter. This example
+//! demonstrates the three ways environment variables feed configuration:
+//! `${VAR}` interpolation inside the file (with `:-default` fallbacks and a
+//! clear error naming any unset variable), `Config::from_env()` building
+//! the whole config from `HELIOS_*` variables, and the overlay where env
+//! vars override file values when both are present.
+//!
+//! ## Prerequisites
+//!
+//! `config.toml` can reference the environment directly:
+//!
+//! ```toml
+//! [llm]
+//! api_key = "${OPENAI_API_KEY}"
+//! base_url = "${HELIOS_BASE_URL:-https://api.openai.com/v1}"
+//! ```
+
+use helios_engine::{Agent, Config};
+
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - Env Config Example");
+    println!("=====================================\n");
+
+    // --- Example 1: Interpolation in the file ---
+    println!("Example 1: ${{VAR}} Interpolation");
+    println!("================================\n");
+
+    // ${OPENAI_API_KEY} is resolved at parse time; if it's unset and has
+    // no :-default, this errors naming the missing variable.
+    match Config::from_file("config.toml") {
+        Ok(config) => println!("✓ Loaded; base_url = {}\n", config.llm.base_url),
+        Err(e) => println!("⚠ {}\n", e),
+    }
+
+    // --- Example 2: Config entirely from the environment ---
+    println!("Example 2: Config::from_env()");
+    println!("=============================\n");
+
+    // Reads HELIOS_MODEL, HELIOS_BASE_URL, HELIOS_API_KEY,
+    // HELIOS_TEMPERATURE, HELIOS_MAX_TOKENS, HELIOS_LOCAL_MODEL_PATH, ...
+    std::env::set_var("HELIOS_MODEL", "gpt-4o-mini");
+    std::env::set_var("HELIOS_TEMPERATURE", "0.3");
+
+    let config = Config::from_env()?;
+    println!("model:       {}", config.llm.model_name);
+    println!("temperature: {}\n", config.llm.temperature);
+
+    // --- Example 3: Env overlay on top of the file ---
+    println!("Example 3: Overlay");
+    println!("==================\n");
+
+    // File values are the base; any HELIOS_* variable that is set wins.
+    std::env::set_var("HELIOS_MAX_TOKENS", "512");
+    let config = Config::from_file("config.toml")?;
+    println!("max_tokens (from env, overriding the file): {}\n", config.llm.max_tokens);
+
+    let mut agent = Agent::builder("assistant")
+        .config(config)
+        .system_prompt("You are a helpful assistant.")
+        .build()
+        .await?;
+
+    let response = agent.chat("Say hello.").await?;
+    println!("Agent: {}", response);
+
+    Ok(())
+}
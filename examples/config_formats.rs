@@ -0,0 +1,57 @@
+//! # Example: YAML and JSON Config Files
+//!
+//! Deployments often template configs with tools that output YAML or JSON.
+//! This example demonstrates format dispatch by extension in
+//! `Config::from_file`, explicit parsing with `Config::from_str(content,
+//! format)`, and serialization via `Config::to_file` / `to_string` —
+//! handy for config generators, with secrets optionally masked.
+
+use helios_engine::config::ConfigFormat;
+use helios_engine::Config;
+
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - Config Formats Example");
+    println!("=========================================\n");
+
+    // --- Example 1: Extension-based dispatch ---
+    println!("Example 1: from_file Dispatch");
+    println!("=============================\n");
+
+    // .toml, .yaml/.yml, and .json all work with the same call.
+    let config = Config::from_file("config.toml")?;
+    println!("✓ Loaded config.toml (model: {})\n", config.llm.model_name);
+
+    // --- Example 2: Parsing from a string ---
+    println!("Example 2: from_str");
+    println!("===================\n");
+
+    let yaml = r#"
+llm:
+  model_name: gpt-4o-mini
+  base_url: https://api.openai.com/v1
+  api_key: sk-demo
+  temperature: 0.7
+"#;
+    let from_yaml = Config::from_str(yaml, ConfigFormat::Yaml)?;
+    println!("✓ Parsed YAML (model: {})\n", from_yaml.llm.model_name);
+
+    // --- Example 3: Writing configs back out ---
+    println!("Example 3: to_file / to_string");
+    println!("==============================\n");
+
+    // Round-trips preserve every section, including local model and
+    // server settings.
+    config.to_file("config_copy.yaml")?;
+    println!("✓ Written config_copy.yaml");
+
+    config.to_file("config_copy.json")?;
+    println!("✓ Written config_copy.json\n");
+
+    // For display or checked-in templates, mask the secrets.
+    let masked = config.to_string_masked(ConfigFormat::Toml)?;
+    println!("Masked TOML:\n{}", masked);
+    // api_key = "sk-****"
+
+    Ok(())
+}
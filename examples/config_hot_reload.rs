@@ -0,0 +1,60 @@
+//! # Example: Hot-Reloadable Configuration
+//!
+//! Rotating an API key or retuning sampling shouldn't require a restart.
+//! This example demonstrates `Config::watch(path)` (notify-based, behind
+//! the `watch` feature): the file is re-parsed on change, validated, and
+//! safe deltas — API key, base URL, temperature, max_tokens — are applied
+//! to the running agent live. Changing the provider type or local model
+//! path triggers a controlled client rebuild instead; a config that fails
+//! validation is never applied. Every reload, successful or not, emits a
+//! tracing event.
+//!
+//! Run it, then edit `config.toml` in another terminal and watch the
+//! change take effect mid-conversation.
+
+use helios_engine::config::ReloadOutcome;
+use helios_engine::{Agent, Config};
+
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    tracing_subscriber::fmt::init();
+
+    println!("🚀 Helios Engine - Hot Reload Example");
+    println!("=====================================\n");
+
+    // The watcher hands out a live handle; the agent subscribes to it so
+    // later turns pick up applied changes automatically.
+    let watched = Config::watch("config.toml")?;
+
+    let mut agent = Agent::builder("assistant")
+        .watched_config(watched.clone())
+        .system_prompt("You are a helpful assistant.")
+        .build()
+        .await?;
+
+    // Log what each file change did — applied live, rebuilt, or rejected.
+    watched.on_reload(|outcome| match outcome {
+        ReloadOutcome::Applied { changed } => {
+            println!("✓ reloaded live: {:?}", changed)
+        }
+        ReloadOutcome::Rebuilt => println!("✓ provider changed; client rebuilt"),
+        ReloadOutcome::Rejected { error } => println!("⚠ reload rejected: {}", error),
+    });
+
+    println!("Chatting; edit config.toml to change temperature or keys live.");
+    println!("Type 'quit' to exit.\n");
+
+    let stdin = std::io::stdin();
+    loop {
+        let mut line = String::new();
+        stdin.read_line(&mut line)?;
+        let line = line.trim();
+        if line == "quit" {
+            break;
+        }
+        let response = agent.chat(line).await?;
+        println!("Agent: {}\n", response);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,42 @@
+//! # Example: Interactive Config Generator
+//!
+//! Writing a correct `config.toml` by hand trips up new users. This
+//! example runs `Config::init_interactive()`: it prompts for provider type
+//! (OpenAI / Azure / Ollama / LM Studio / local GGUF), base URL, API key
+//! (hidden input), model name, and sampling defaults, validates the
+//! answers, optionally makes a test call, and writes `config.toml`. An
+//! existing file triggers a merge/overwrite prompt, and passing
+//! `--defaults` skips all prompts and emits the Ollama-localhost template
+//! for CI.
+//!
+//! ```bash
+//! cargo run --example config_init              # interactive
+//! cargo run --example config_init -- --defaults  # non-interactive template
+//! ```
+
+use helios_engine::Config;
+
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - Config Generator");
+    println!("===================================\n");
+
+    if std::env::args().any(|arg| arg == "--defaults") {
+        // CI path: no prompts, sensible local defaults.
+        let config = Config::ollama_localhost_template();
+        config.to_file("config.toml")?;
+        println!("✓ Written config.toml (Ollama localhost template)");
+        return Ok(());
+    }
+
+    // Prompts on stdin; API keys are read with hidden input and never
+    // echoed. If config.toml already exists you'll be offered a merge.
+    let config = Config::init_interactive().await?;
+
+    println!("\n✓ config.toml written");
+    println!("  provider: {}", config.llm.base_url);
+    println!("  model:    {}", config.llm.model_name);
+    println!("\nRun any example to try it: cargo run --example simple_chat");
+
+    Ok(())
+}
@@ -0,0 +1,88 @@
+//! # Example: Context-Overflow Handling
+//!
+//! When the accumulated prompt exceeds a local model's `context_size`,
+//! generation used to fail — or worse, truncate from the wrong end. This
+//! example demonstrates overflow detection before generation:
+//! `LLMClient::count_tokens` measures the prompt with the model's real
+//! tokenizer (an approximation for remote providers), and the agent's
+//! context strategy either trims the oldest messages or errors with the
+//! exact prompt-tokens-vs-context-size numbers.
+//!
+//! Note: This example requires the `local` feature to be enabled.
+//! Run with: cargo run --example context_overflow --features local
+
+#[cfg(not(feature = "local"))]
+fn main() {
+    eprintln!("❌ This example requires the 'local' feature to be enabled.");
+    eprintln!("Run with: cargo run --example context_overflow --features local");
+    std::process::exit(1);
+}
+
+#[cfg(feature = "local")]
+use helios_engine::config::LocalConfig;
+#[cfg(feature = "local")]
+use helios_engine::llm::ContextStrategy;
+#[cfg(feature = "local")]
+use helios_engine::{ChatMessage, LLMClient};
+
+#[cfg(feature = "local")]
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - Context Overflow Example");
+    println!("===========================================\n");
+
+    // A deliberately tiny window to make overflow easy to hit.
+    let local_config = LocalConfig {
+        huggingface_repo: "unsloth/Qwen2.5-0.5B-Instruct-GGUF".to_string(),
+        model_file: "Qwen2.5-0.5B-Instruct-Q4_K_M.gguf".to_string(),
+        context_size: 512,
+        temperature: 0.7,
+        max_tokens: 128,
+        ..Default::default()
+    };
+
+    println!("📥 Loading model (context_size = 512)...");
+    let client = LLMClient::new(helios_engine::llm::LLMProviderType::Local(local_config)).await?;
+    println!("✓ Loaded\n");
+
+    // Build a conversation guaranteed to overflow the window.
+    let mut messages = vec![ChatMessage::system("You are a helpful assistant.")];
+    for i in 0..50 {
+        messages.push(ChatMessage::user(format!(
+            "Message {}: tell me something about the history of computing.",
+            i
+        )));
+        messages.push(ChatMessage::assistant("Computing history is long and varied."));
+    }
+
+    // --- Example 1: Budget proactively ---
+    println!("Example 1: count_tokens");
+    println!("=======================\n");
+
+    let tokens = client.count_tokens(&messages)?;
+    println!("prompt: {} tokens, window: 512\n", tokens);
+
+    // --- Example 2: Strict mode errors with precise numbers ---
+    println!("Example 2: ContextStrategy::Error");
+    println!("=================================\n");
+
+    match client
+        .chat_with_strategy(messages.clone(), None, None, ContextStrategy::Error)
+        .await
+    {
+        Ok(_) => println!("unexpectedly fit"),
+        // "prompt is 1342 tokens but context size is 512"
+        Err(e) => println!("⚠ {}\n", e),
+    }
+
+    // --- Example 3: Trim the oldest messages, keep the system prompt ---
+    println!("Example 3: ContextStrategy::TrimOldest");
+    println!("======================================\n");
+
+    let response = client
+        .chat_with_strategy(messages, None, None, ContextStrategy::TrimOldest)
+        .await?;
+    println!("Assistant (after graceful trimming): {}", response.content);
+
+    Ok(())
+}
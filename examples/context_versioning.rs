@@ -0,0 +1,81 @@
+//! # Example: Shared Context Versioning
+//!
+//! Agents sometimes overwrite each other's keys in `SharedContext` without
+//! anyone noticing. This example demonstrates per-key version counters with
+//! last-writer attribution: `set` records the writing agent and a
+//! timestamp, `get_with_meta` exposes them, and `set_if_version` provides
+//! compare-and-set so concurrent edits are detected instead of silently
+//! lost. `subscribe()` delivers `ContextChange` events so a monitor can
+//! react to updates without polling.
+
+use helios_engine::SharedContext;
+
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - Context Versioning Example");
+    println!("=============================================\n");
+
+    let context = SharedContext::new();
+
+    // --- Example 1: Versions and attribution ---
+    println!("Example 1: Versions and Attribution");
+    println!("===================================\n");
+
+    context.set_as("researcher", "findings", "Initial notes").await;
+    context.set_as("writer", "findings", "Cleaned-up notes").await;
+
+    let entry = context.get_with_meta("findings").await.unwrap();
+    println!("value:       {}", entry.value);
+    println!("version:     {}", entry.version);
+    println!("last writer: {}", entry.last_writer);
+    println!("written at:  {}\n", entry.updated_at);
+
+    // --- Example 2: Compare-and-set ---
+    println!("Example 2: Compare-and-Set");
+    println!("==========================\n");
+
+    let entry = context.get_with_meta("findings").await.unwrap();
+
+    // Someone else writes in between...
+    context.set_as("editor", "findings", "Editor's rewrite").await;
+
+    // ...so our stale-version write is rejected rather than clobbering it.
+    match context
+        .set_if_version("findings", "Based on old notes", entry.version)
+        .await
+    {
+        Ok(new_version) => println!("updated to version {}", new_version),
+        Err(conflict) => println!(
+            "⚠ conflict: expected v{}, current is v{} (written by {})",
+            conflict.expected, conflict.current, conflict.last_writer
+        ),
+    }
+    println!();
+
+    // --- Example 3: Change notifications ---
+    println!("Example 3: Change Notifications");
+    println!("===============================\n");
+
+    let mut changes = context.subscribe();
+
+    let monitor = tokio::spawn(async move {
+        while let Ok(change) = changes.recv().await {
+            println!(
+                "change: {} set '{}' (v{})",
+                change.writer, change.key, change.version
+            );
+            if change.key == "done" {
+                break;
+            }
+        }
+    });
+
+    context.set_as("researcher", "progress", "50%").await;
+    context.set_as("researcher", "progress", "100%").await;
+    context.set_as("coordinator", "done", "true").await;
+
+    monitor.await.ok();
+    println!("\n✓ Monitor observed all writes without polling");
+
+    Ok(())
+}
@@ -0,0 +1,89 @@
+//! # Example: Cost Tracking
+//!
+//! Token counts are only half the story — what does a forest run cost in
+//! dollars? This example demonstrates the pricing table in `Config`
+//! (per-model $/1K prompt and completion tokens), per-agent cost
+//! accumulation, `Forest::cost_report()` broken down by agent and task, and
+//! the `max_cost` budget on `ForestBuilder` that aborts a run with
+//! `BudgetExceeded` before it gets expensive.
+//!
+//! ## Prerequisites
+//!
+//! Add pricing to your `config.toml`:
+//!
+//! ```toml
+//! [pricing."gpt-4o"]
+//! prompt_per_1k = 0.0025
+//! completion_per_1k = 0.01
+//! ```
+
+use helios_engine::{Agent, Config, ForestBuilder, HeliosError};
+
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - Cost Tracking Example");
+    println!("========================================\n");
+
+    let config = Config::from_file("config.toml")?;
+
+    let mut forest = ForestBuilder::new()
+        .config(config)
+        .agent(
+            "coordinator".to_string(),
+            Agent::builder("coordinator").system_prompt("You plan and delegate."),
+        )
+        .agent(
+            "researcher".to_string(),
+            Agent::builder("researcher").system_prompt("You research."),
+        )
+        .agent(
+            "writer".to_string(),
+            Agent::builder("writer").system_prompt("You write."),
+        )
+        // Abort the run once accumulated cost crosses this line.
+        .max_cost(0.50)
+        .build()
+        .await?;
+
+    let outcome = forest
+        .execute_collaborative_task_detailed(
+            &"coordinator".to_string(),
+            "Produce a one-page brief on battery recycling.".to_string(),
+            vec!["researcher".to_string(), "writer".to_string()],
+        )
+        .await;
+
+    match outcome {
+        Ok(result) => {
+            println!("Result: {}\n", result.final_answer);
+            println!("Total cost: ${:.4}\n", result.total_cost);
+        }
+        Err(HeliosError::BudgetExceeded { spent, budget }) => {
+            println!("⚠ Aborted: spent ${:.4} of a ${:.4} budget\n", spent, budget);
+        }
+        Err(e) => return Err(e),
+    }
+
+    // --- Breakdown by agent and by task ---
+    println!("Cost Report");
+    println!("===========\n");
+
+    let report = forest.cost_report();
+
+    println!("By agent:");
+    for (agent, cost) in &report.by_agent {
+        println!(
+            "  {:<12} ${:.4} ({} prompt / {} completion tokens)",
+            agent, cost.total, cost.prompt_tokens, cost.completion_tokens
+        );
+    }
+
+    println!("\nBy task:");
+    for (task_id, cost) in &report.by_task {
+        println!("  {:<10} ${:.4}", task_id, cost.total);
+    }
+
+    println!("\nGrand total: ${:.4}", report.total);
+
+    Ok(())
+}
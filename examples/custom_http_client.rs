@@ -0,0 +1,82 @@
+//! # Example: Bring-Your-Own HTTP Client and TLS
+//!
+//! Corporate environments need a proxy, a custom CA bundle, and sometimes
+//! mTLS to reach an internal LLM gateway. This example demonstrates
+//! injecting HTTP/TLS configuration into `LLMClient`, `OpenAIEmbeddings`,
+//! the Qdrant store, and `HttpRequestTool` — either as a preconfigured
+//! `reqwest::Client` or as a declarative `HttpClientConfig`, with one shared
+//! client reused across components.
+//!
+//! The same settings are available without code under `[http]` in
+//! config.toml:
+//!
+//! ```toml
+//! [http]
+//! proxy = "http://proxy.corp.example:3128"
+//! ca_bundle = "/etc/ssl/corp-ca.pem"
+//! connect_timeout_secs = 10
+//! read_timeout_secs = 120
+//! ```
+
+use helios_engine::config::LLMConfig;
+use helios_engine::http::HttpClientConfig;
+use helios_engine::llm::LLMProviderType;
+use helios_engine::{HttpRequestTool, LLMClient, OpenAIEmbeddings};
+
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - Custom HTTP Client Example");
+    println!("=============================================\n");
+
+    // --- Example 1: Declarative HttpClientConfig ---
+    println!("Example 1: HttpClientConfig");
+    println!("===========================\n");
+
+    let http_config = HttpClientConfig::default()
+        .proxy("http://proxy.corp.example:3128")
+        .add_root_cert_pem_file("/etc/ssl/corp-ca.pem")
+        .client_identity_pem_file("/etc/ssl/agent-client.pem")
+        .connect_timeout_secs(10)
+        .read_timeout_secs(120)
+        .pool_max_idle_per_host(8);
+
+    // One shared client, reused by every component that takes it.
+    let shared_client = http_config.build_client()?;
+    println!("✓ Shared reqwest::Client built with proxy + corporate CA + mTLS\n");
+
+    // --- Example 2: Inject into the LLM client ---
+    println!("Example 2: LLMClient");
+    println!("====================\n");
+
+    let llm_config = LLMConfig {
+        model_name: "internal-model".to_string(),
+        base_url: "https://llm-gateway.corp.example/v1".to_string(),
+        api_key: std::env::var("GATEWAY_API_KEY").unwrap_or_default(),
+        temperature: 0.7,
+        max_tokens: 2048,
+    };
+
+    let client = LLMClient::new(LLMProviderType::Remote(llm_config))
+        .await?
+        .with_http_client(shared_client.clone());
+    println!("✓ LLMClient using the shared client\n");
+
+    // --- Example 3: Embeddings and tools take the same client ---
+    println!("Example 3: Embeddings and HttpRequestTool");
+    println!("=========================================\n");
+
+    let embeddings = OpenAIEmbeddings::new(
+        "https://llm-gateway.corp.example/v1/embeddings".to_string(),
+        std::env::var("GATEWAY_API_KEY").unwrap_or_default(),
+    )
+    .with_http_client(shared_client.clone());
+
+    let http_tool = HttpRequestTool::new().with_http_client(shared_client);
+    println!("✓ Embeddings and HttpRequestTool share the connection pool\n");
+
+    let _ = (client, embeddings, http_tool);
+    println!("All outbound traffic now flows through the corporate proxy");
+    println!("with the custom CA bundle and client identity applied.");
+
+    Ok(())
+}
@@ -0,0 +1,89 @@
+//! # Example: Date/Time Utility Tool
+//!
+//! Models are notoriously bad at date arithmetic. This example demonstrates
+//! the `DateTimeTool`, which offloads date/time work to chrono. The tool
+//! selects an operation via an `operation` string parameter:
+//!
+//! - `now` — current time in a given timezone
+//! - `add` — add a duration (with unit) to an ISO-8601 timestamp
+//! - `diff` — difference between two ISO-8601 timestamps in a given unit
+//! - `parse` — parse common natural formats into ISO-8601
+//! - `format` — format a timestamp with a strftime pattern
+//!
+//! Argument combinations are validated per operation, and ambiguous or
+//! invalid timezones produce helpful error text listing valid examples.
+
+use helios_engine::{Agent, Config, DateTimeTool, Tool};
+
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - DateTime Tool Example");
+    println!("========================================\n");
+
+    // --- Example 1: Call the tool directly ---
+    println!("Example 1: Direct Tool Calls");
+    println!("============================\n");
+
+    let tool = DateTimeTool;
+
+    let result = tool
+        .execute(serde_json::json!({
+            "operation": "now",
+            "timezone": "Asia/Tokyo"
+        }))
+        .await?;
+    println!("now (Tokyo): {}", result.output);
+
+    let result = tool
+        .execute(serde_json::json!({
+            "operation": "diff",
+            "start": "2026-01-01T00:00:00Z",
+            "end": "2026-08-27T12:00:00Z",
+            "unit": "days"
+        }))
+        .await?;
+    println!("diff: {}", result.output);
+
+    let result = tool
+        .execute(serde_json::json!({
+            "operation": "add",
+            "timestamp": "2026-08-27T09:30:00Z",
+            "amount": 90,
+            "unit": "minutes"
+        }))
+        .await?;
+    println!("add: {}", result.output);
+
+    // Invalid timezones come back as errors with a few valid examples, so
+    // the model can correct itself.
+    let result = tool
+        .execute(serde_json::json!({
+            "operation": "now",
+            "timezone": "Mars/Olympus_Mons"
+        }))
+        .await?;
+    println!("invalid timezone: {}\n", result.output);
+
+    // --- Example 2: Let an agent do the date math ---
+    println!("Example 2: Agent-Driven Date Math");
+    println!("=================================\n");
+
+    let config = Config::from_file("config.toml")?;
+
+    let mut agent = Agent::builder("SchedulingAssistant")
+        .config(config)
+        .system_prompt(
+            "You are a scheduling assistant. Always use the datetime tool \
+             for any date or time calculation — never compute dates yourself.",
+        )
+        .tool(Box::new(DateTimeTool))
+        .build()
+        .await?;
+
+    let response = agent
+        .chat("If a meeting starts 2026-09-15T14:00:00Z and runs 3 hours, when does it end in New York time?")
+        .await?;
+    println!("Agent: {}", response);
+
+    Ok(())
+}
@@ -0,0 +1,70 @@
+//! # Example: Delegation Depth Limits
+//!
+//! Two agents can delegate to each other forever — the coordinator hands a
+//! task to the writer, the writer hands it back. This example demonstrates
+//! the delegation chain tracked in shared context: each `delegate_task`
+//! call records its chain of delegators, delegation to an agent already in
+//! the chain is rejected, and `ForestBuilder::max_delegation_depth(n)` caps
+//! how deep chains can grow. Rejections come back as tool results with an
+//! explanation, so the agent completes the work itself instead of erroring.
+
+use helios_engine::{Agent, Config, ForestBuilder};
+
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - Delegation Limits Example");
+    println!("============================================\n");
+
+    let config = Config::from_file("config.toml")?;
+
+    let mut forest = ForestBuilder::new()
+        .config(config)
+        .agent(
+            "coordinator".to_string(),
+            Agent::builder("coordinator")
+                .system_prompt("You delegate writing work whenever possible."),
+        )
+        .agent(
+            "writer".to_string(),
+            // A deliberately lazy agent that tries to delegate back — the
+            // chain check turns that into a polite refusal it must handle.
+            Agent::builder("writer")
+                .system_prompt("Prefer delegating back to the coordinator if you can."),
+        )
+        .agent(
+            "editor".to_string(),
+            Agent::builder("editor").system_prompt("You polish drafts."),
+        )
+        // coordinator → writer → editor is fine; anything deeper is refused.
+        .max_delegation_depth(2)
+        .build()
+        .await?;
+
+    let result = forest
+        .execute_collaborative_task_detailed(
+            &"coordinator".to_string(),
+            "Write and polish a release announcement for v2.0.".to_string(),
+            vec!["writer".to_string(), "editor".to_string()],
+        )
+        .await?;
+
+    println!("Final Answer");
+    println!("============\n");
+    println!("{}\n", result.final_answer);
+
+    // --- The delegation chains that actually occurred ---
+    println!("Delegation Chains");
+    println!("=================\n");
+    for chain in &result.delegation_chains {
+        // e.g. coordinator -> writer -> editor, or a rejected entry like
+        // writer -> coordinator (rejected: coordinator already in chain).
+        println!("{}", chain);
+    }
+
+    // The raw chain state is also visible in shared context while running.
+    if let Some(chains) = forest.shared_context().get("delegation:chains").await {
+        println!("\nShared context record:\n{}", chains);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,66 @@
+//! # Example: Differential Provider Testing
+//!
+//! Prompts that must work on both GPT-4o and a local Qwen tend to regress
+//! only in production. This example demonstrates the differential harness in
+//! the evals module: the same scenario suite runs against two configured
+//! providers and produces a comparison report — tool-call agreement rate
+//! (same tool, compatible args), answer similarity (embedding cosine plus an
+//! optional judge), latency and cost deltas, and per-scenario divergences
+//! with transcripts. The remote side can replay recorded cassettes while the
+//! local side runs live.
+//!
+//! The harness writes a JSON artifact and a Markdown summary, and exits
+//! non-zero above a configurable divergence threshold so it can gate
+//! releases in CI.
+
+use helios_engine::evals::{DifferentialHarness, Scenario};
+use helios_engine::Config;
+
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - Differential Eval Example");
+    println!("============================================\n");
+
+    let remote_config = Config::from_file("config.toml")?;
+    let local_config = Config::from_file("config.local.toml")?;
+
+    let scenarios = vec![
+        Scenario::new("calc", "What is 15% of 2400?").expect_tool("calculator"),
+        Scenario::new("file", "What does config.toml contain?").expect_tool("file_read"),
+        Scenario::new("plain", "Explain ownership in one paragraph."),
+    ];
+
+    let harness = DifferentialHarness::new(remote_config, local_config)
+        .scenarios(scenarios)
+        // The remote side replays cassettes recorded earlier, so CI needs
+        // no API key; the local side runs the GGUF model live.
+        .replay_baseline("evals/cassettes")
+        .judge_similarity(true)
+        .divergence_threshold(0.15);
+
+    let report = harness.run().await?;
+
+    // --- Summary ---
+    println!("Comparison Report");
+    println!("=================\n");
+    println!("tool-call agreement: {:.0}%", report.tool_agreement * 100.0);
+    println!("answer similarity:   {:.2}", report.mean_similarity);
+    println!("latency delta:       {:?}", report.latency_delta);
+    println!("cost delta:          ${:.4}\n", report.cost_delta);
+
+    for divergence in &report.divergences {
+        println!("✗ scenario '{}': {}", divergence.scenario, divergence.summary);
+    }
+
+    report.write_json("eval_report.json")?;
+    report.write_markdown("eval_report.md")?;
+    println!("\n✓ Artifacts written: eval_report.json, eval_report.md");
+
+    // Gate the release: non-zero exit above the threshold.
+    if report.exceeds_threshold() {
+        eprintln!("Divergence above threshold — failing the build.");
+        std::process::exit(1);
+    }
+
+    Ok(())
+}
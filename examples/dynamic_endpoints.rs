@@ -0,0 +1,82 @@
+//! # Example: Dynamic Custom Endpoints
+//!
+//! Static `CustomEndpoint` entries can only return a fixed JSON blob. This
+//! example demonstrates `DynamicEndpoint`: an endpoint carrying an async
+//! handler with access to the served agent, so it can run a one-off
+//! `agent.chat` or inspect the tool registry per request. Path parameters
+//! (`/api/docs/:id`) and query strings are extracted for you, static
+//! endpoints keep working alongside, and duplicate method+path
+//! registrations are rejected at startup.
+//!
+//! ```bash
+//! curl http://localhost:8080/api/summarize -d '{"text": "..."}'
+//! curl http://localhost:8080/api/docs/42?format=short
+//! curl http://localhost:8080/api/tools
+//! ```
+
+use helios_engine::serve::{self, CustomEndpointsConfig, DynamicEndpoint, StatusCode};
+use helios_engine::tools::CalculatorTool;
+use helios_engine::{Agent, Config};
+
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - Dynamic Endpoints Example");
+    println!("============================================\n");
+
+    let config = Config::from_file("config.toml")?;
+
+    let agent = Agent::builder("helios")
+        .config(config)
+        .system_prompt("You are a concise summarizer.")
+        .tool(Box::new(CalculatorTool))
+        .build()
+        .await?;
+
+    let endpoints = CustomEndpointsConfig::new()
+        // A handler that runs the agent for a one-off completion.
+        .dynamic(DynamicEndpoint::post("/api/summarize", |req, agent| async move {
+            let text = req.json()["text"].as_str().unwrap_or_default().to_string();
+            match agent.lock().await.chat(format!("Summarize: {}", text)).await {
+                Ok(summary) => (StatusCode::OK, serde_json::json!({ "summary": summary })),
+                Err(e) => (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    serde_json::json!({ "error": e.to_string() }),
+                ),
+            }
+        }))
+        // Path parameters and query strings are parsed for you.
+        .dynamic(DynamicEndpoint::get("/api/docs/:id", |req, _agent| async move {
+            let id = req.param("id").unwrap_or_default();
+            let format = req.query("format").unwrap_or_else(|| "full".to_string());
+            (
+                StatusCode::OK,
+                serde_json::json!({ "id": id, "format": format }),
+            )
+        }))
+        // Introspect the served agent's tool registry.
+        .dynamic(DynamicEndpoint::get("/api/tools", |_req, agent| async move {
+            let names: Vec<String> = agent
+                .lock()
+                .await
+                .tools()
+                .iter()
+                .map(|t| t.name().to_string())
+                .collect();
+            (StatusCode::OK, serde_json::json!({ "tools": names }))
+        }))
+        // Static endpoints still work side by side.
+        .static_json("/api/version", serde_json::json!({ "version": "2.0" }));
+
+    println!("Serving dynamic endpoints on http://localhost:8080\n");
+
+    // Registering the same method+path twice would fail here, at startup.
+    serve::start_server_with_agent_and_custom_endpoints(
+        agent,
+        "helios".to_string(),
+        "127.0.0.1:8080",
+        endpoints,
+    )
+    .await?;
+
+    Ok(())
+}
@@ -0,0 +1,78 @@
+//! # Example: Embedding Cache
+//!
+//! Repeatedly embedding the same queries and documents across runs burns
+//! API credits. This example demonstrates the embedding cache layer that
+//! wraps any `EmbeddingsProvider`: an in-memory LRU by default, with
+//! optional on-disk persistence, keyed by SHA-256 of the input text plus
+//! the model name. Hit/miss counters, size limits, and per-call bypass are
+//! all exposed. `RAGSystem::with_embedding_cache` wires it in without
+//! touching existing constructors.
+//!
+//! ## Prerequisites
+//!
+//! ```sh
+//! export OPENAI_API_KEY=your-key
+//! ```
+
+use helios_engine::rag::EmbeddingCacheConfig;
+use helios_engine::{Document, InMemoryVectorStore, OpenAIEmbeddings, RAGSystem};
+
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - Embedding Cache Example");
+    println!("==========================================\n");
+
+    let embeddings = OpenAIEmbeddings::new(
+        "https://api.openai.com/v1/embeddings".to_string(),
+        std::env::var("OPENAI_API_KEY").unwrap_or_default(),
+    );
+
+    // LRU capped at 10k entries / 64 MB, persisted to disk so cache hits
+    // survive restarts.
+    let cache_config = EmbeddingCacheConfig::default()
+        .max_entries(10_000)
+        .max_bytes(64 * 1024 * 1024)
+        .persist_to("./embedding_cache");
+
+    let vector_store = InMemoryVectorStore::new(embeddings);
+    let mut rag_system = RAGSystem::new(vector_store).with_embedding_cache(cache_config);
+
+    // --- Example 1: Repeated embeds hit the cache ---
+    println!("Example 1: Cache Hits");
+    println!("=====================\n");
+
+    rag_system
+        .add_documents(vec![Document {
+            id: "doc".to_string(),
+            content: "Helios Engine caches embeddings by content hash.".to_string(),
+            metadata: std::collections::HashMap::new(),
+        }])
+        .await?;
+
+    // Same query three times: one API call, two cache hits.
+    for _ in 0..3 {
+        rag_system.search("how does embedding caching work?", 1).await?;
+    }
+
+    let stats = rag_system.embedding_cache_stats();
+    println!("hits:   {}", stats.hits);
+    println!("misses: {}", stats.misses);
+    println!("bytes:  {}\n", stats.bytes);
+
+    // --- Example 2: Bypass per call ---
+    println!("Example 2: Bypass");
+    println!("=================\n");
+
+    // Force a fresh embedding (e.g. after switching embedding models
+    // mid-experiment) without clearing the cache.
+    rag_system
+        .search_uncached("how does embedding caching work?", 1)
+        .await?;
+    println!("✓ bypassed the cache for one call\n");
+
+    // The key includes the model name, so switching models never serves a
+    // vector computed by a different model.
+    println!("cache key = SHA-256(text) + model name");
+
+    Ok(())
+}
@@ -0,0 +1,79 @@
+//! # Example: Typed Errors
+//!
+//! Opaque error strings make it impossible to branch on "rate limited"
+//! versus "invalid API key" versus "model not found". This example
+//! demonstrates the refined `HeliosError` variants — `Auth`,
+//! `RateLimited { retry_after }`, `ContextLengthExceeded { limit,
+//! requested }`, `ProviderError { status, body }`, `ToolError { tool,
+//! source }`, `ConfigError { field, reason }` — each carrying the provider
+//! name and raw response body where relevant, plus the `is_retryable()`
+//! and `is_user_error()` helpers the retry policy and Forest task retry
+//! use.
+
+use std::time::Duration;
+
+use helios_engine::{Agent, Config, HeliosError};
+
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - Typed Errors Example");
+    println!("=======================================\n");
+
+    let config = Config::from_file("config.toml")?;
+
+    let mut agent = Agent::builder("assistant")
+        .config(config)
+        .system_prompt("You are a helpful assistant.")
+        .build()
+        .await?;
+
+    // --- Example 1: Branch on the variant ---
+    println!("Example 1: Matching Variants");
+    println!("============================\n");
+
+    match agent.chat("Hello!").await {
+        Ok(response) => println!("Agent: {}\n", response),
+        Err(HeliosError::Auth { provider, .. }) => {
+            println!("⚠ bad credentials for {} — fix the API key, don't retry\n", provider);
+        }
+        Err(HeliosError::RateLimited { retry_after, .. }) => {
+            let wait = retry_after.unwrap_or(Duration::from_secs(5));
+            println!("⚠ rate limited; retrying after {:?}\n", wait);
+        }
+        Err(HeliosError::ContextLengthExceeded { limit, requested, .. }) => {
+            println!("⚠ prompt needs {} tokens but the limit is {}\n", requested, limit);
+        }
+        Err(HeliosError::ProviderError { status, body, provider }) => {
+            println!("⚠ {} returned {}: {}\n", provider, status, body);
+        }
+        Err(e) => println!("⚠ {}\n", e),
+    }
+
+    // --- Example 2: Generic retry loop via the helpers ---
+    println!("Example 2: is_retryable / is_user_error");
+    println!("=======================================\n");
+
+    let mut attempts = 0;
+    let response = loop {
+        attempts += 1;
+        match agent.chat("Summarize the Rust ownership model.").await {
+            Ok(response) => break response,
+            // Transient (rate limits, 5xx, timeouts): back off and retry.
+            Err(e) if e.is_retryable() && attempts < 3 => {
+                println!("attempt {} failed ({}); retrying...", attempts, e);
+                tokio::time::sleep(Duration::from_secs(2 * attempts)).await;
+            }
+            // User errors (bad key, bad config) will never succeed on
+            // retry — surface them immediately.
+            Err(e) if e.is_user_error() => {
+                println!("✗ not retryable: {}", e);
+                return Err(e);
+            }
+            Err(e) => return Err(e),
+        }
+    };
+
+    println!("Agent: {}", response);
+
+    Ok(())
+}
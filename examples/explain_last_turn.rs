@@ -0,0 +1,74 @@
+//! # Example: Explain the Last Turn
+//!
+//! Support engineers ask "why did the bot say that?" — this example shows
+//! the one-call answer. `agent.explain_last_turn()` assembles a structured
+//! explanation from data the run already captured: context sources included
+//! by the assembly planner (memory, RAG chunks with scores, shared context
+//! keys), tools called with results, finish reason, guardrail verdicts,
+//! model and prompt version, and token/cost. An optional LLM-written
+//! narrative is generated *from that structure*, never from scratch.
+//!
+//! The explanation is serializable for support tickets, and serve exposes
+//! the most recent turn of a session via a debug endpoint.
+
+use helios_engine::{Agent, CalculatorTool, Config, MemoryDBTool};
+
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - Explain Last Turn Example");
+    println!("============================================\n");
+
+    let config = Config::from_file("config.toml")?;
+
+    let mut agent = Agent::builder("ExplainableAgent")
+        .config(config)
+        .system_prompt("You are a helpful assistant.")
+        .tool(Box::new(CalculatorTool))
+        .tool(Box::new(MemoryDBTool::new()))
+        .build()
+        .await?;
+
+    agent.set_memory("customer_tier", "premium");
+
+    // A turn that touches memory and a tool, so the explanation has
+    // something to show.
+    let response = agent
+        .chat("I'm a premium customer — what's 15% off of $240?")
+        .await?;
+    println!("Agent: {}\n", response);
+
+    // --- Structured explanation ---
+    println!("Structured Explanation");
+    println!("======================\n");
+
+    let explanation = agent.explain_last_turn().await?;
+
+    println!("model:          {}", explanation.model);
+    println!("finish reason:  {}", explanation.finish_reason);
+    println!("tokens (in/out): {}/{}", explanation.tokens_in, explanation.tokens_out);
+
+    println!("\ncontext sources:");
+    for source in &explanation.context_sources {
+        println!("  - {} ({})", source.description, source.kind);
+    }
+
+    println!("\ntool calls:");
+    for call in &explanation.tool_calls {
+        println!("  - {} → {}", call.tool_name, call.output_preview);
+    }
+
+    // --- Optional narrative, grounded in the structure above ---
+    println!("\nNarrative");
+    println!("=========\n");
+
+    let narrative = explanation.narrative().await?;
+    println!("{}\n", narrative);
+
+    // Serialize the whole thing for a support ticket.
+    let json = serde_json::to_string_pretty(&explanation)?;
+    std::fs::write("last_turn_explanation.json", json)?;
+    println!("✓ Written to last_turn_explanation.json");
+    println!("  (over HTTP: GET /debug/sessions/:id/last_turn)");
+
+    Ok(())
+}
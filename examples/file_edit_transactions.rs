@@ -0,0 +1,100 @@
+//! # Example: File Edit Dry-Runs and Transactions
+//!
+//! Letting a model find/replace in files blind is scary. This example
+//! demonstrates the safer `FileEditTool`:
+//!
+//! - `dry_run` returns a unified diff of what *would* change, without writing
+//! - an `edits` array applies multiple find/replace operations on one file
+//!   atomically (all or nothing, written via temp file + rename)
+//! - `expect_unique: true` refuses when the `find` string matches zero times
+//!   or more than once, reporting the match count
+//! - optional `.bak` backups are controlled at tool construction
+
+use helios_engine::{Agent, Config, FileEditTool, Tool};
+
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - File Edit Transactions Example");
+    println!("=================================================\n");
+
+    std::fs::write(
+        "scratch.txt",
+        "version = 1\nname = example\nversion_note = draft\n",
+    )?;
+
+    // Keep a .bak copy of any file before the first edit touches it.
+    let tool = FileEditTool::new().with_backups(true);
+
+    // --- Example 1: Dry-run preview ---
+    println!("Example 1: dry_run");
+    println!("==================\n");
+
+    let result = tool
+        .execute(serde_json::json!({
+            "path": "scratch.txt",
+            "find": "name = example",
+            "replace": "name = renamed",
+            "dry_run": true
+        }))
+        .await?;
+    println!("diff preview:\n{}\n", result.output);
+    // The file is untouched until a non-dry-run call is made.
+
+    // --- Example 2: expect_unique guards ambiguous edits ---
+    println!("Example 2: expect_unique");
+    println!("========================\n");
+
+    // "version" appears twice; with expect_unique the tool refuses and
+    // reports the count so the model can refine its find string.
+    let result = tool
+        .execute(serde_json::json!({
+            "path": "scratch.txt",
+            "find": "version",
+            "replace": "release",
+            "expect_unique": true
+        }))
+        .await?;
+    println!("{}\n", result.output);
+
+    // --- Example 3: Atomic multi-edit transaction ---
+    println!("Example 3: edits array");
+    println!("======================\n");
+
+    let result = tool
+        .execute(serde_json::json!({
+            "path": "scratch.txt",
+            "edits": [
+                {"find": "version = 1", "replace": "version = 2"},
+                {"find": "version_note = draft", "replace": "version_note = final"}
+            ]
+        }))
+        .await?;
+    println!("{}\n", result.output);
+    // If any single edit had failed, none would have been written.
+
+    // --- Example 4: An agent using the safe workflow ---
+    println!("Example 4: Agent-Driven Editing");
+    println!("===============================\n");
+
+    let config = Config::from_file("config.toml")?;
+
+    let mut agent = Agent::builder("Editor")
+        .config(config)
+        .system_prompt(
+            "You edit files. ALWAYS dry_run first, inspect the diff, then \
+             apply. Use expect_unique for single-occurrence replacements.",
+        )
+        .tool(Box::new(FileEditTool::new().with_backups(true)))
+        .build()
+        .await?;
+
+    let response = agent
+        .chat("In scratch.txt, change the name to 'helios-demo'.")
+        .await?;
+    println!("Agent: {}", response);
+
+    std::fs::remove_file("scratch.txt").ok();
+    std::fs::remove_file("scratch.txt.bak").ok();
+
+    Ok(())
+}
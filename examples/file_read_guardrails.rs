@@ -0,0 +1,81 @@
+//! # Example: File Read Guardrails
+//!
+//! Reading a whole file into the chat context is wasteful and sometimes
+//! dangerous (huge or binary files). This example demonstrates the extended
+//! `FileReadTool`:
+//!
+//! - `start_line` / `end_line` arguments for ranged reads (out-of-range
+//!   values clamp rather than error)
+//! - a default line/byte cap, with the actual file size reported in output
+//! - binary detection via null-byte sniffing, returning a descriptive
+//!   message with size and mime guess instead of dumping garbage
+//! - an optional `encoding` argument with lossy conversion as the fallback
+
+use helios_engine::{Agent, Config, FileReadTool, Tool};
+
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - File Read Guardrails Example");
+    println!("===============================================\n");
+
+    let tool = FileReadTool;
+
+    // --- Example 1: Ranged reads ---
+    println!("Example 1: Line Ranges");
+    println!("======================\n");
+
+    let result = tool
+        .execute(serde_json::json!({
+            "path": "README.md",
+            "start_line": 1,
+            "end_line": 20
+        }))
+        .await?;
+    println!("lines 1-20:\n{}\n", result.output);
+
+    // Out-of-range values clamp: asking for lines 5000-6000 of a 100-line
+    // file returns the tail of the file with a note, not an error.
+    let result = tool
+        .execute(serde_json::json!({
+            "path": "README.md",
+            "start_line": 5000,
+            "end_line": 6000
+        }))
+        .await?;
+    println!("clamped range:\n{}\n", result.output);
+
+    // --- Example 2: Binary detection ---
+    println!("Example 2: Binary Detection");
+    println!("===========================\n");
+
+    let result = tool
+        .execute(serde_json::json!({
+            "path": "public/helios-logo.png"
+        }))
+        .await?;
+    // Output looks like: "binary file (image/png, 48 KB) — not displayed"
+    println!("{}\n", result.output);
+
+    // --- Example 3: Agent reading a large file safely ---
+    println!("Example 3: Agent with Guardrails");
+    println!("================================\n");
+
+    let config = Config::from_file("config.toml")?;
+
+    let mut agent = Agent::builder("SafeReader")
+        .config(config)
+        .system_prompt(
+            "You read files. For large files, read them in ranges rather \
+             than all at once — the tool reports the total size.",
+        )
+        .tool(Box::new(FileReadTool))
+        .build()
+        .await?;
+
+    let response = agent
+        .chat("What are the first dependencies listed in package-lock.json?")
+        .await?;
+    println!("Agent: {}", response);
+
+    Ok(())
+}
@@ -0,0 +1,78 @@
+//! # Example: Advanced File Search
+//!
+//! `FileSearchTool` now honors `.gitignore` by default (via the `ignore`
+//! crate), so it no longer wades through `target/` or `node_modules/`. This
+//! example demonstrates the new arguments:
+//!
+//! - `glob` — restrict matches to a pattern like `src/**/*.rs`
+//! - `content_regex` — grep-style content search with line numbers and a
+//!   max-matches cap
+//! - `include_ignored` — opt back into searching ignored paths
+//!
+//! Results include the file path, matched line number, and a trimmed line
+//! preview; binary files are skipped automatically.
+
+use helios_engine::{Agent, Config, FileSearchTool, Tool};
+
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - Advanced File Search Example");
+    println!("===============================================\n");
+
+    // --- Example 1: Direct tool calls ---
+    println!("Example 1: Direct Tool Calls");
+    println!("============================\n");
+
+    let tool = FileSearchTool;
+
+    // Glob mode: find Rust sources, respecting .gitignore.
+    let result = tool
+        .execute(serde_json::json!({
+            "glob": "src/**/*.rs"
+        }))
+        .await?;
+    println!("glob results:\n{}\n", result.output);
+
+    // Content-regex mode: grep for TODOs with line numbers, capped.
+    let result = tool
+        .execute(serde_json::json!({
+            "glob": "**/*.rs",
+            "content_regex": "TODO|FIXME",
+            "max_matches": 20
+        }))
+        .await?;
+    println!("content_regex results:\n{}\n", result.output);
+
+    // Opt back into ignored paths when you really mean it.
+    let result = tool
+        .execute(serde_json::json!({
+            "glob": "target/**/*.d",
+            "include_ignored": true,
+            "max_matches": 5
+        }))
+        .await?;
+    println!("include_ignored results:\n{}\n", result.output);
+
+    // --- Example 2: Agent-driven search ---
+    println!("Example 2: Agent-Driven Search");
+    println!("==============================\n");
+
+    let config = Config::from_file("config.toml")?;
+
+    let mut agent = Agent::builder("CodeSearcher")
+        .config(config)
+        .system_prompt(
+            "You search codebases. Prefer content_regex for 'find where X \
+             happens' questions and glob for 'list the files' questions.",
+        )
+        .tool(Box::new(FileSearchTool))
+        .build()
+        .await?;
+
+    let response = agent
+        .chat("Where in this project is the Tool trait implemented?")
+        .await?;
+    println!("Agent: {}", response);
+
+    Ok(())
+}
@@ -0,0 +1,101 @@
+//! # Example: File Write Modes and Root Confinement
+//!
+//! `FileWriteTool` now supports a `mode` argument — `create_new` (fail if
+//! the file exists), `overwrite` (atomic via temp file + rename), and
+//! `append` — plus a `create_dirs` flag for missing parents. Constructed
+//! with `FileWriteTool::rooted(path)`, the tool refuses to write outside
+//! that root, stopping path traversal like `../../etc/cron.d/x`. Results
+//! report bytes written and whether the file was created or modified.
+
+use helios_engine::{Agent, Config, FileWriteTool, Tool};
+
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - File Write Modes Example");
+    println!("===========================================\n");
+
+    // All writes are confined to ./workspace; traversal out of it refuses.
+    std::fs::create_dir_all("workspace")?;
+    let tool = FileWriteTool::rooted("workspace");
+
+    // --- Example 1: create_new with directory creation ---
+    println!("Example 1: create_new + create_dirs");
+    println!("===================================\n");
+
+    let result = tool
+        .execute(serde_json::json!({
+            "path": "notes/today.md",
+            "content": "# Notes\n",
+            "mode": "create_new",
+            "create_dirs": true
+        }))
+        .await?;
+    println!("{}\n", result.output); // "created notes/today.md (8 bytes)"
+
+    // Running create_new again fails cleanly instead of clobbering.
+    let result = tool
+        .execute(serde_json::json!({
+            "path": "notes/today.md",
+            "content": "oops",
+            "mode": "create_new"
+        }))
+        .await?;
+    println!("{}\n", result.output);
+
+    // --- Example 2: append and overwrite ---
+    println!("Example 2: append / overwrite");
+    println!("=============================\n");
+
+    let result = tool
+        .execute(serde_json::json!({
+            "path": "notes/today.md",
+            "content": "- first item\n",
+            "mode": "append"
+        }))
+        .await?;
+    println!("{}\n", result.output);
+
+    // Overwrite is atomic: the new content lands via temp file + rename, so
+    // readers never observe a half-written file.
+    let result = tool
+        .execute(serde_json::json!({
+            "path": "notes/today.md",
+            "content": "# Notes (rewritten)\n",
+            "mode": "overwrite"
+        }))
+        .await?;
+    println!("{}\n", result.output);
+
+    // --- Example 3: traversal is refused ---
+    println!("Example 3: Root Confinement");
+    println!("===========================\n");
+
+    let result = tool
+        .execute(serde_json::json!({
+            "path": "../../etc/cron.d/x",
+            "content": "nope",
+            "mode": "overwrite"
+        }))
+        .await?;
+    println!("{}\n", result.output);
+
+    // --- Example 4: In an agent ---
+    println!("Example 4: Agent Writing Files");
+    println!("==============================\n");
+
+    let config = Config::from_file("config.toml")?;
+
+    let mut agent = Agent::builder("Writer")
+        .config(config)
+        .system_prompt("You manage files inside the workspace directory.")
+        .tool(Box::new(FileWriteTool::rooted("workspace")))
+        .build()
+        .await?;
+
+    let response = agent
+        .chat("Add a line 'reviewed' to notes/today.md without losing its contents.")
+        .await?;
+    println!("Agent: {}", response);
+
+    Ok(())
+}
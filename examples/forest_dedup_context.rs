@@ -0,0 +1,79 @@
+//! # Example: Deduplicated Shared Context Embeddings
+//!
+//! In forests using the shared RAG knowledge base, multiple agents store
+//! near-identical findings and retrieval gets cluttered with copies. This
+//! example demonstrates the deduplication pass on the shared store path: new
+//! documents are compared against recent shared documents by embedding
+//! cosine similarity, and a configurable policy decides what happens:
+//!
+//! - `Merge` — append the new agent's attribution to the existing document
+//! - `Link` — store with a `duplicate_of` reference, excluded from default search
+//! - `StoreAnyway` — keep the old behavior
+//!
+//! The `update_task_memory` tool reports which action was taken, so agents
+//! know their finding already existed.
+
+use helios_engine::forest::DedupPolicy;
+use helios_engine::{Agent, Config, ForestBuilder};
+
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - Shared Context Deduplication Example");
+    println!("=======================================================\n");
+
+    let config = Config::from_file("config.toml")?;
+
+    let mut forest = ForestBuilder::new()
+        .config(config)
+        .agent(
+            "coordinator".to_string(),
+            Agent::builder("coordinator")
+                .system_prompt("You coordinate research tasks and delegate to both researchers."),
+        )
+        .agent(
+            "researcher_a".to_string(),
+            Agent::builder("researcher_a")
+                .system_prompt("You research topics and save findings with update_task_memory."),
+        )
+        .agent(
+            "researcher_b".to_string(),
+            Agent::builder("researcher_b")
+                .system_prompt("You research topics and save findings with update_task_memory."),
+        )
+        // Merge near-duplicates (cosine >= 0.92 within the same plan run)
+        // instead of storing a second copy.
+        .shared_context_dedup(DedupPolicy::Merge { threshold: 0.92 })
+        .build()
+        .await?;
+
+    println!("✓ Forest created with dedup policy: Merge (threshold 0.92)\n");
+
+    // Both researchers will likely surface overlapping facts; the second
+    // near-identical finding is merged into the first with both agents
+    // listed in the document's attribution metadata.
+    let result = forest
+        .execute_collaborative_task(
+            &"coordinator".to_string(),
+            "Have both researchers independently list the key properties of \
+             Rust's ownership model, then combine their findings."
+                .to_string(),
+            vec!["researcher_a".to_string(), "researcher_b".to_string()],
+        )
+        .await?;
+    println!("Result: {}\n", result);
+
+    // --- Inspect what the dedup pass did ---
+    println!("Dedup Metrics");
+    println!("=============\n");
+
+    let metrics = forest.dedup_metrics();
+    println!("documents stored: {}", metrics.stored);
+    println!("merged:           {}", metrics.merged);
+    println!("linked:           {}", metrics.linked);
+
+    // The other policies are one-line changes:
+    //   .shared_context_dedup(DedupPolicy::Link { threshold: 0.95 })
+    //   .shared_context_dedup(DedupPolicy::StoreAnyway)
+
+    Ok(())
+}
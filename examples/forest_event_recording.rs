@@ -0,0 +1,75 @@
+//! # Example: Forest Event Recording
+//!
+//! When an overnight forest run fails at 3am, the in-memory events are gone
+//! by the time you look. This example demonstrates the `ForestEventRecorder`,
+//! which subscribes to the forest event stream and appends each event as a
+//! JSON line (with a schema version) to a file in real time, flushing per
+//! event and rotating by size. A loader reconstructs a timeline and summary
+//! statistics from a recording, so post-mortems don't require the original
+//! process.
+
+use helios_engine::forest::{ForestEventRecorder, ForestRecording};
+use helios_engine::{Agent, Config, ForestBuilder};
+
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - Forest Event Recording Example");
+    println!("=================================================\n");
+
+    let config = Config::from_file("config.toml")?;
+
+    // Record every forest event to a JSONL file, rotating at 50 MB.
+    let recorder = ForestEventRecorder::to_file("forest_run.jsonl")?.rotate_at_mb(50);
+
+    let mut forest = ForestBuilder::new()
+        .config(config)
+        .agent(
+            "coordinator".to_string(),
+            Agent::builder("coordinator")
+                .system_prompt("You coordinate team projects and delegate tasks."),
+        )
+        .agent(
+            "researcher".to_string(),
+            Agent::builder("researcher").system_prompt("You research and analyze information."),
+        )
+        .event_recorder(recorder)
+        .build()
+        .await?;
+
+    println!("✓ Forest created, recording to forest_run.jsonl\n");
+
+    let result = forest
+        .execute_collaborative_task(
+            &"coordinator".to_string(),
+            "Write a short overview of renewable energy trends.".to_string(),
+            vec!["researcher".to_string()],
+        )
+        .await?;
+    println!("Result: {}\n", result);
+
+    // --- Post-mortem: load the recording back and summarize it ---
+    // This works in a fresh process long after the run finished.
+    println!("Post-Mortem Analysis");
+    println!("====================\n");
+
+    let recording = ForestRecording::load("forest_run.jsonl")?;
+    let summary = recording.summary();
+
+    println!("Schema version: {}", recording.schema_version());
+    println!("Events recorded: {}", recording.events().len());
+    println!("Total duration:  {:?}", summary.total_duration);
+    println!("Retries:         {}", summary.retries);
+    println!("Tokens used:     {}", summary.total_tokens);
+
+    for task in summary.task_durations {
+        println!("  task {:<12} {:?}", task.id, task.duration);
+    }
+
+    // The transcript exporter accepts a recording directly, so a readable
+    // transcript can be produced from the file alone.
+    let transcript = recording.export_transcript()?;
+    std::fs::write("forest_run_transcript.md", transcript)?;
+    println!("\n✓ Transcript written to forest_run_transcript.md");
+
+    Ok(())
+}
@@ -0,0 +1,81 @@
+//! # Example: Forest Progress Events
+//!
+//! The only visibility into a running forest used to be println output from
+//! inside the library. This example demonstrates the typed event stream:
+//! `Forest::subscribe()` returns a receiver of `ForestEvent`s —
+//! `PlanCreated`, `TaskStarted`, `TaskCompleted`, `TaskFailed`,
+//! `AgentMessage`, `AgentStreamChunk`, `RunCompleted` — suitable for
+//! building a TUI or web progress view. Events are emitted without blocking
+//! execution: the channel is bounded and drops oldest when the receiver is
+//! slow.
+
+use helios_engine::forest::ForestEvent;
+use helios_engine::{Agent, Config, ForestBuilder};
+
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - Forest Events Example");
+    println!("========================================\n");
+
+    let config = Config::from_file("config.toml")?;
+
+    let mut forest = ForestBuilder::new()
+        .config(config)
+        .agent(
+            "coordinator".to_string(),
+            Agent::builder("coordinator").system_prompt("You plan and delegate."),
+        )
+        .agent(
+            "worker".to_string(),
+            Agent::builder("worker").system_prompt("You complete assigned tasks."),
+        )
+        .build()
+        .await?;
+
+    // Subscribe before starting; multiple subscribers are allowed.
+    let mut events = forest.subscribe();
+
+    // A consumer task rendering progress — in a real app this would drive a
+    // TUI or push over a websocket.
+    let renderer = tokio::spawn(async move {
+        while let Some(event) = events.recv().await {
+            match event {
+                ForestEvent::PlanCreated { tasks, .. } => {
+                    println!("📋 plan created: {} tasks", tasks);
+                }
+                ForestEvent::TaskStarted { task_id, agent } => {
+                    println!("▶ {} started by {}", task_id, agent);
+                }
+                ForestEvent::TaskCompleted { task_id, result_preview } => {
+                    println!("✓ {} done: {}", task_id, result_preview);
+                }
+                ForestEvent::TaskFailed { task_id, error } => {
+                    println!("✗ {} failed: {}", task_id, error);
+                }
+                ForestEvent::AgentMessage { from, to } => {
+                    println!("✉ {} → {}", from, to.unwrap_or_else(|| "all".into()));
+                }
+                ForestEvent::AgentStreamChunk { .. } => {
+                    // High-volume; a TUI would render these into a live pane.
+                }
+                ForestEvent::RunCompleted { duration } => {
+                    println!("🏁 run completed in {:?}", duration);
+                    break;
+                }
+            }
+        }
+    });
+
+    let result = forest
+        .execute_collaborative_task(
+            &"coordinator".to_string(),
+            "List three tips for writing good commit messages.".to_string(),
+            vec!["worker".to_string()],
+        )
+        .await?;
+
+    renderer.await.ok();
+    println!("\nFinal result: {}", result);
+
+    Ok(())
+}
@@ -0,0 +1,91 @@
+//! # Example: Forest Message Pump with Timeouts
+//!
+//! `forest.process_messages()` used to be a manual, all-or-nothing call: if
+//! one recipient agent hung while reacting to a delivered message, the whole
+//! batch stalled. This example demonstrates the background message pump with
+//! per-delivery timeouts, continued delivery to other recipients when one
+//! times out, and a heartbeat event for detecting a stalled pump. Manual
+//! `process_messages()` remains available and now returns a
+//! `DeliveryReport` with per-recipient timing and timeout flags.
+
+use std::time::Duration;
+
+use helios_engine::{Agent, Config, ForestBuilder};
+
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - Forest Message Pump Example");
+    println!("==============================================\n");
+
+    let config = Config::from_file("config.toml")?;
+
+    let mut forest = ForestBuilder::new()
+        .config(config)
+        .agent(
+            "coordinator".to_string(),
+            Agent::builder("coordinator").system_prompt("You coordinate the team."),
+        )
+        .agent(
+            "fast_worker".to_string(),
+            Agent::builder("fast_worker").system_prompt("You respond quickly and briefly."),
+        )
+        .agent(
+            "slow_worker".to_string(),
+            Agent::builder("slow_worker").system_prompt("You research deeply before responding."),
+        )
+        // Each individual delivery gets 30 seconds; a timeout on one
+        // recipient does not block delivery to the others.
+        .delivery_timeout(Duration::from_secs(30))
+        .build()
+        .await?;
+
+    // --- Example 1: Background pump with heartbeat ---
+    println!("Example 1: Background Pump");
+    println!("==========================\n");
+
+    let pump = forest.start_message_pump(Duration::from_millis(500));
+    pump.on_heartbeat(|beat| {
+        println!("💓 pump alive, {} messages pending", beat.pending);
+    });
+    println!("✓ Pump started (500ms interval)\n");
+
+    forest
+        .send_message(
+            &"coordinator".to_string(),
+            None, // broadcast
+            "Status check: report what you are working on.".to_string(),
+        )
+        .await?;
+
+    // Messages are delivered by the pump in the background.
+    tokio::time::sleep(Duration::from_secs(5)).await;
+
+    // Stopping is clean; pending messages stay queued for the next pump or a
+    // manual process_messages() call. The pump also stops on forest drop.
+    pump.stop().await;
+    println!("✓ Pump stopped\n");
+
+    // --- Example 2: Manual delivery with a report ---
+    println!("Example 2: Manual process_messages");
+    println!("==================================\n");
+
+    forest
+        .send_message(
+            &"coordinator".to_string(),
+            Some(&"slow_worker".to_string()),
+            "Summarize your research so far.".to_string(),
+        )
+        .await?;
+
+    let report = forest.process_messages().await?;
+    for delivery in report.deliveries {
+        println!(
+            "→ {:<12} {:?}{}",
+            delivery.recipient,
+            delivery.duration,
+            if delivery.timed_out { "  (timed out)" } else { "" },
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,79 @@
+//! # Example: Parallel Execution of Independent Forest Tasks
+//!
+//! `execute_collaborative_task` used to run plan tasks strictly
+//! sequentially even when the dependency graph allowed parallelism. This
+//! example demonstrates the parallel executor: ready tasks (all
+//! dependencies satisfied) run concurrently up to `max_parallel_tasks`,
+//! task status and shared memory update safely under the existing locks,
+//! and tasks assigned to the same agent still serialize because an agent's
+//! `ChatSession` can't be used concurrently. One branch failing does not
+//! cancel unrelated branches unless `fail_fast` is set.
+
+use helios_engine::{Agent, Config, ForestBuilder};
+
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - Parallel Forest Tasks Example");
+    println!("================================================\n");
+
+    let config = Config::from_file("config.toml")?;
+
+    let mut forest = ForestBuilder::new()
+        .config(config)
+        .agent(
+            "coordinator".to_string(),
+            Agent::builder("coordinator").system_prompt(
+                "Create a plan where research and data-collection tasks have \
+                 no dependency on each other, then a synthesis task depends on both.",
+            ),
+        )
+        .agent(
+            "researcher".to_string(),
+            Agent::builder("researcher").system_prompt("You research background material."),
+        )
+        .agent(
+            "collector".to_string(),
+            Agent::builder("collector").system_prompt("You collect numeric data."),
+        )
+        .agent(
+            "synthesizer".to_string(),
+            Agent::builder("synthesizer").system_prompt("You combine inputs into a final answer."),
+        )
+        // Up to 2 independent tasks at once; keep going if one branch fails.
+        .max_parallel_tasks(2)
+        .fail_fast(false)
+        .build()
+        .await?;
+
+    println!("✓ Forest created (max_parallel_tasks = 2)\n");
+
+    let start = std::time::Instant::now();
+    let result = forest
+        .execute_collaborative_task(
+            &"coordinator".to_string(),
+            "Produce a brief on electric vehicle adoption: background research \
+             and sales data can be gathered independently, then synthesized."
+                .to_string(),
+            vec![
+                "researcher".to_string(),
+                "collector".to_string(),
+                "synthesizer".to_string(),
+            ],
+        )
+        .await?;
+
+    println!("Result ({:?}):\n{}\n", start.elapsed(), result);
+
+    // The execution timeline shows the researcher and collector tasks
+    // overlapping, while the synthesis task waited for both:
+    println!("Task Timeline");
+    println!("=============\n");
+    for task in forest.last_plan_timeline() {
+        println!(
+            "{:<12} {:>8?} → {:>8?}  ({})",
+            task.id, task.started_at_offset, task.finished_at_offset, task.status
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,76 @@
+//! # Example: Mid-Execution Re-Planning
+//!
+//! Plans used to be created once and executed blindly — if a research task
+//! revealed the plan was wrong, nothing could adjust. This example
+//! demonstrates the optional re-planning phase: after each task completes
+//! (or fails), the coordinator sees the updated shared memory and may call
+//! the `revise_plan` tool to add, remove, re-order, or reassign remaining
+//! tasks. `max_replans` guards against infinite loops, and every revision
+//! is recorded in shared context for auditing.
+
+use helios_engine::{Agent, Config, ForestBuilder};
+
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - Re-Planning Example");
+    println!("======================================\n");
+
+    let config = Config::from_file("config.toml")?;
+
+    let mut forest = ForestBuilder::new()
+        .config(config)
+        .agent(
+            "coordinator".to_string(),
+            Agent::builder("coordinator")
+                .system_prompt(
+                    "Create an initial plan with create_plan. After each task, \
+                     review the results in shared memory — if they change what's \
+                     needed, use revise_plan to adjust the remaining tasks.",
+                )
+                .max_iterations(20),
+        )
+        .agent(
+            "researcher".to_string(),
+            Agent::builder("researcher")
+                .system_prompt("Research tasks. Save findings with update_task_memory."),
+        )
+        .agent(
+            "writer".to_string(),
+            Agent::builder("writer").system_prompt("Write up results from shared memory."),
+        )
+        // Enable the re-planning phase, but allow at most 3 revisions per run.
+        .enable_replanning(true)
+        .max_replans(3)
+        .build()
+        .await?;
+
+    println!("✓ Forest created with re-planning enabled (max 3 revisions)\n");
+
+    // A task where the initial plan often proves wrong: the research step
+    // may reveal that a comparison section is needed, prompting a revision
+    // that adds a task for it.
+    let result = forest
+        .execute_collaborative_task(
+            &"coordinator".to_string(),
+            "Write a recommendation: should we adopt sqlite or postgres for a \
+             small internal tool? Adjust the plan if research uncovers factors \
+             the original plan didn't cover."
+                .to_string(),
+            vec!["researcher".to_string(), "writer".to_string()],
+        )
+        .await?;
+    println!("Result: {}\n", result);
+
+    // --- Audit how the plan evolved ---
+    println!("Plan Revision History");
+    println!("=====================\n");
+
+    for (i, revision) in forest.plan_revisions().iter().enumerate() {
+        println!("revision {}: {}", i, revision.summary);
+        println!("  added:      {:?}", revision.added_tasks);
+        println!("  removed:    {:?}", revision.removed_tasks);
+        println!("  reassigned: {:?}", revision.reassigned_tasks);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,74 @@
+//! # Example: Plan Persistence and Resumable Tasks
+//!
+//! Long multi-agent runs sometimes die halfway — a crash, a rate limit,
+//! Ctrl-C — and everything used to be lost. This example demonstrates
+//! `Forest::save_state` and `Forest::resume`: the shared context, the
+//! `TaskPlan` with per-task statuses and results, and each agent's
+//! `ChatSession` are serialized; resuming continues from the first
+//! non-completed task. Completed results are never re-run, and tasks that
+//! were `InProgress` at crash time are reset to `Pending`.
+
+use helios_engine::{Agent, Config, Forest, ForestBuilder};
+
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - Forest Resume Example");
+    println!("========================================\n");
+
+    let config = Config::from_file("config.toml")?;
+
+    let mut forest = ForestBuilder::new()
+        .config(config.clone())
+        .agent(
+            "coordinator".to_string(),
+            Agent::builder("coordinator").system_prompt("You plan and coordinate."),
+        )
+        .agent(
+            "worker1".to_string(),
+            Agent::builder("worker1").system_prompt("You complete assigned tasks."),
+        )
+        .agent(
+            "worker2".to_string(),
+            Agent::builder("worker2").system_prompt("You complete assigned tasks."),
+        )
+        // Snapshot automatically after every task completes.
+        .autosave_state("forest_state.json")
+        .build()
+        .await?;
+
+    println!("✓ Forest created with autosave to forest_state.json\n");
+
+    // --- A run that might get interrupted ---
+    println!("Starting collaborative task (interrupt with Ctrl-C to test resume)...\n");
+
+    let run = forest.execute_collaborative_task(
+        &"coordinator".to_string(),
+        "Write a three-part guide to onboarding new engineers.".to_string(),
+        vec!["worker1".to_string(), "worker2".to_string()],
+    );
+
+    match run.await {
+        Ok(result) => {
+            println!("Completed in one go: {}", result);
+        }
+        Err(e) => {
+            println!("Run interrupted: {}\n", e);
+
+            // --- Resume in a "new process" ---
+            println!("Resuming from forest_state.json...\n");
+
+            let mut forest = Forest::resume("forest_state.json", config).await?;
+
+            // Tasks already Completed keep their results; the task that was
+            // InProgress at the crash is back to Pending and re-runs.
+            for task in forest.current_plan_tasks() {
+                println!("  {:<10} {}", task.status, task.id);
+            }
+
+            let result = forest.continue_collaborative_task().await?;
+            println!("\nResumed to completion: {}", result);
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,95 @@
+//! # Example: Consensus and Voting
+//!
+//! Sometimes the best answer comes from giving the same task to N agents
+//! independently and letting a judge pick or merge. This example
+//! demonstrates `Forest::execute_with_voting` with both strategies:
+//! `JudgeAgent(agent_id)` for open-ended answers and `Majority` for
+//! classification-style outputs. Candidate runs execute in parallel, the
+//! individual answers are stored in shared context under well-known keys,
+//! and the returned result includes all candidates plus the judge's
+//! rationale.
+
+use helios_engine::forest::VotingStrategy;
+use helios_engine::{Agent, Config, ForestBuilder};
+
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - Voting Example");
+    println!("=================================\n");
+
+    let config = Config::from_file("config.toml")?;
+
+    let mut forest = ForestBuilder::new()
+        .config(config)
+        .agent(
+            "optimist".to_string(),
+            Agent::builder("optimist").system_prompt("You emphasize opportunities."),
+        )
+        .agent(
+            "skeptic".to_string(),
+            Agent::builder("skeptic").system_prompt("You emphasize risks."),
+        )
+        .agent(
+            "pragmatist".to_string(),
+            Agent::builder("pragmatist").system_prompt("You focus on practical tradeoffs."),
+        )
+        .agent(
+            "judge".to_string(),
+            Agent::builder("judge")
+                .system_prompt("Compare candidate answers and select or merge the best, explaining why."),
+        )
+        .build()
+        .await?;
+
+    // --- Example 1: Judge-selected answer ---
+    println!("Example 1: JudgeAgent Strategy");
+    println!("==============================\n");
+
+    let result = forest
+        .execute_with_voting(
+            "Should a five-person startup adopt microservices?".to_string(),
+            vec![
+                "optimist".to_string(),
+                "skeptic".to_string(),
+                "pragmatist".to_string(),
+            ],
+            VotingStrategy::JudgeAgent("judge".to_string()),
+        )
+        .await?;
+
+    println!("Winner: {}\n", result.answer);
+    println!("Judge's rationale: {}\n", result.rationale.unwrap_or_default());
+
+    for candidate in &result.candidates {
+        println!("--- candidate from {} ---", candidate.agent);
+        println!("{}\n", candidate.answer);
+    }
+    // Candidates also live in shared context under voting:candidate:<agent>.
+
+    // --- Example 2: Majority for classification ---
+    println!("Example 2: Majority Strategy");
+    println!("============================\n");
+
+    let result = forest
+        .execute_with_voting(
+            "Classify this ticket as bug, feature, or question: \
+             'The export button crashes the app.'"
+                .to_string(),
+            vec![
+                "optimist".to_string(),
+                "skeptic".to_string(),
+                "pragmatist".to_string(),
+            ],
+            VotingStrategy::Majority,
+        )
+        .await?;
+
+    println!(
+        "Majority answer: {} ({} of {} votes)",
+        result.answer,
+        result.vote_count.unwrap_or(0),
+        result.candidates.len()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,78 @@
+//! # Example: Answer Format Contracts
+//!
+//! API consumers often specify constraints like "max 280 characters, plain
+//! text, no markdown" — and the model ignores them a third of the time. This
+//! example demonstrates `FormatContract` on `ChatOptions`: a post-generation
+//! validator checks the answer, and on violation the agent makes one
+//! automatic corrective regeneration pass that quotes the violations back to
+//! the model. Persistent violations are reported on the run outcome and can
+//! optionally be hard-truncated at a sentence boundary.
+//!
+//! The serve module maps the `max_completion_chars` and `format` request
+//! fields onto the same contract, so HTTP clients get it for free.
+
+use helios_engine::chat::{ChatOptions, FormatContract};
+use helios_engine::{Agent, Config};
+
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - Format Contracts Example");
+    println!("===========================================\n");
+
+    let config = Config::from_file("config.toml")?;
+
+    let mut agent = Agent::builder("ConciseAssistant")
+        .config(config)
+        .system_prompt("You are a helpful assistant.")
+        .build()
+        .await?;
+
+    // --- Example 1: Tweet-length, plain text ---
+    println!("Example 1: 280 Characters, No Markdown");
+    println!("======================================\n");
+
+    let contract = FormatContract::new()
+        .max_chars(280)
+        .forbid_markdown()
+        .truncate_on_failure(true);
+
+    let options = ChatOptions::default().format_contract(contract);
+
+    let response = agent
+        .chat_with_options("Explain what Rust's borrow checker does.", options)
+        .await?;
+    println!("Agent ({} chars): {}\n", response.len(), response);
+
+    // --- Example 2: Require a bullet list ---
+    println!("Example 2: Bullet List Required");
+    println!("===============================\n");
+
+    let contract = FormatContract::new().max_words(120).require_bullet_list();
+
+    let response = agent
+        .chat_with_options(
+            "What are the main benefits of async Rust?",
+            ChatOptions::default().format_contract(contract),
+        )
+        .await?;
+    println!("Agent: {}\n", response);
+
+    // --- Example 3: Inspect contract violations on the run outcome ---
+    println!("Example 3: Violation Reporting");
+    println!("==============================\n");
+
+    let outcome = agent.last_run_outcome();
+    if outcome.contract_violations().is_empty() {
+        println!("✓ Contract satisfied (possibly after a corrective pass)");
+    } else {
+        for violation in outcome.contract_violations() {
+            println!("✗ {}", violation);
+        }
+    }
+    println!(
+        "Corrective regenerations used: {}",
+        outcome.corrective_passes
+    );
+
+    Ok(())
+}
@@ -0,0 +1,81 @@
+//! # Example: Chat Templates for Local Models
+//!
+//! Different GGUF models expect different prompt templates — ChatML,
+//! Llama-3, Mistral, Gemma — and the wrong one produces garbage or leaked
+//! special tokens. This example demonstrates the `chat_template` field on
+//! `LocalConfig`: built-in templates via `ChatTemplate`, a
+//! `Custom(String)` variant using a minijinja-style template over the
+//! message list, and auto-detection from GGUF metadata when the model file
+//! declares one. The templates cover the system message, multi-turn
+//! history, and the tool-call prompt format the agent loop uses.
+//!
+//! Note: This example requires the `local` feature to be enabled.
+//! Run with: cargo run --example local_chat_templates --features local
+
+#[cfg(not(feature = "local"))]
+fn main() {
+    eprintln!("❌ This example requires the 'local' feature to be enabled.");
+    eprintln!("Run with: cargo run --example local_chat_templates --features local");
+    std::process::exit(1);
+}
+
+#[cfg(feature = "local")]
+use helios_engine::config::{ChatTemplate, LocalConfig};
+#[cfg(feature = "local")]
+use helios_engine::{ChatMessage, LLMClient};
+
+#[cfg(feature = "local")]
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - Chat Templates Example");
+    println!("=========================================\n");
+
+    // --- Example 1: Explicit built-in template ---
+    println!("Example 1: Built-In Templates");
+    println!("=============================\n");
+
+    let local_config = LocalConfig {
+        huggingface_repo: "unsloth/Qwen2.5-0.5B-Instruct-GGUF".to_string(),
+        model_file: "Qwen2.5-0.5B-Instruct-Q4_K_M.gguf".to_string(),
+        context_size: 2048,
+        temperature: 0.7,
+        max_tokens: 256,
+        // Qwen models speak ChatML. Also available: Llama3, Mistral,
+        // Gemma, and Auto (the default) which reads GGUF metadata.
+        chat_template: ChatTemplate::ChatML,
+        ..Default::default()
+    };
+
+    // Render without loading the model to see exactly what will be fed in
+    // — useful when debugging leaked special tokens.
+    let messages = vec![
+        ChatMessage::system("You are a helpful assistant."),
+        ChatMessage::user("Hello!"),
+    ];
+    let rendered = local_config.chat_template.render(&messages)?;
+    println!("Rendered ChatML prompt:\n{}\n", rendered);
+    // <|im_start|>system\nYou are a helpful assistant.<|im_end|>...
+
+    println!("📥 Loading model...");
+    let client = LLMClient::new(helios_engine::llm::LLMProviderType::Local(local_config)).await?;
+    let response = client.chat(messages, None, None).await?;
+    println!("Assistant: {}\n", response.content);
+
+    // --- Example 2: Custom template ---
+    println!("Example 2: Custom Template");
+    println!("==========================\n");
+
+    // Minijinja-style, evaluated over the message list. The agent loop's
+    // tool-call format passes through the same path.
+    let custom = ChatTemplate::Custom(
+        "{% for m in messages %}### {{ m.role }}\n{{ m.content }}\n{% endfor %}### assistant\n"
+            .to_string(),
+    );
+    let rendered = custom.render(&[
+        ChatMessage::system("Be brief."),
+        ChatMessage::user("Why templates?"),
+    ])?;
+    println!("Rendered custom prompt:\n{}", rendered);
+
+    Ok(())
+}
@@ -0,0 +1,78 @@
+//! # Example: Local Embeddings Provider
+//!
+//! Requiring an OpenAI key just for embeddings blocks fully-local setups.
+//! This example demonstrates `LocalEmbeddings` (feature = "local-embeddings"),
+//! a sentence-transformers model downloaded from HuggingFace using the same
+//! download machinery as the local LLM path and cached on disk between runs.
+//! It reports its dimension so `QdrantVectorStore` creates collections with
+//! the right size, and it batches embedding requests.
+//!
+//! Run with:
+//!
+//! ```sh
+//! cargo run --example local_embeddings --features local-embeddings
+//! ```
+
+use helios_engine::rag::LocalEmbeddings;
+use helios_engine::{Agent, Config, Document, RAGSystem, RAGTool};
+
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - Local Embeddings Example");
+    println!("===========================================\n");
+
+    // --- Example 1: Standalone provider ---
+    println!("Example 1: LocalEmbeddings");
+    println!("==========================\n");
+
+    // First run downloads the model to the cache dir; later runs are instant.
+    let embeddings = LocalEmbeddings::from_hf("sentence-transformers/all-MiniLM-L6-v2").await?;
+    println!("✓ Model loaded (dimension: {})", embeddings.dimension());
+
+    // Batching: one call, many texts.
+    let vectors = embeddings
+        .embed_batch(&["first sentence", "second sentence", "third sentence"])
+        .await?;
+    println!("✓ Embedded {} texts locally\n", vectors.len());
+
+    // --- Example 2: Fully local RAG system ---
+    println!("Example 2: Fully Local RAG");
+    println!("==========================\n");
+
+    let mut rag_system = RAGSystem::new_in_memory_with(embeddings);
+    rag_system
+        .add_documents(vec![Document {
+            id: "offline".to_string(),
+            content: "This entire pipeline runs without any external API.".to_string(),
+            metadata: std::collections::HashMap::new(),
+        }])
+        .await?;
+
+    let results = rag_system.search("does this need an API key?", 1).await?;
+    println!("top result: {} (score {:.3})\n", results[0].document.content, results[0].score);
+
+    // --- Example 3: Agent with local RAG tool ---
+    println!("Example 3: Agent with Local RAG Tool");
+    println!("====================================\n");
+
+    let config = Config::from_file("config.toml")?;
+
+    // Convenience constructors mirror the OpenAI ones:
+    //   RAGTool::new_in_memory_local()
+    //   RAGTool::new_qdrant_local("http://localhost:6333", "collection")
+    let rag_tool = RAGTool::new_in_memory_local().await?;
+
+    let mut agent = Agent::builder("OfflineRagAgent")
+        .config(config)
+        .system_prompt("You answer from your local knowledge base.")
+        .tool(Box::new(rag_tool))
+        .build()
+        .await?;
+
+    let response = agent
+        .chat("Store this: 'The build server lives in rack B4.' Then tell me where the build server is.")
+        .await?;
+    println!("Agent: {}", response);
+
+    Ok(())
+}
@@ -0,0 +1,102 @@
+//! # Example: Multiple Local Models
+//!
+//! One process can host a small fast GGUF for routing and a larger one for
+//! final answers. This example demonstrates
+//! `LLMProviderType::LocalMulti`: models load lazily on first use, a call
+//! picks one via `ChatOptions::model("qwen-0.5b")`, `unload(name)` frees
+//! memory, and per-model memory usage is reported. The serve module's
+//! model routing and per-agent model profiles can reference these same
+//! names.
+//!
+//! Note: This example requires the `local` feature to be enabled.
+//! Run with: cargo run --example local_multi_model --features local
+
+#[cfg(not(feature = "local"))]
+fn main() {
+    eprintln!("❌ This example requires the 'local' feature to be enabled.");
+    eprintln!("Run with: cargo run --example local_multi_model --features local");
+    std::process::exit(1);
+}
+
+#[cfg(feature = "local")]
+use helios_engine::config::LocalConfig;
+#[cfg(feature = "local")]
+use helios_engine::llm::ChatOptions;
+#[cfg(feature = "local")]
+use helios_engine::{ChatMessage, LLMClient};
+
+#[cfg(feature = "local")]
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - Multi-Model Example");
+    println!("======================================\n");
+
+    let small = LocalConfig {
+        name: "qwen-0.5b".to_string(),
+        huggingface_repo: "unsloth/Qwen2.5-0.5B-Instruct-GGUF".to_string(),
+        model_file: "Qwen2.5-0.5B-Instruct-Q4_K_M.gguf".to_string(),
+        context_size: 2048,
+        temperature: 0.3,
+        max_tokens: 64,
+        ..Default::default()
+    };
+
+    let large = LocalConfig {
+        name: "qwen-7b".to_string(),
+        huggingface_repo: "unsloth/Qwen2.5-7B-Instruct-GGUF".to_string(),
+        model_file: "Qwen2.5-7B-Instruct-Q4_K_M.gguf".to_string(),
+        context_size: 8192,
+        temperature: 0.7,
+        max_tokens: 1024,
+        ..Default::default()
+    };
+
+    // Nothing is loaded yet — models come up lazily on first use.
+    let client =
+        LLMClient::new(helios_engine::llm::LLMProviderType::LocalMulti(vec![small, large]))
+            .await?;
+
+    // --- Example 1: Route with the small model ---
+    println!("Example 1: Fast Routing Model");
+    println!("=============================\n");
+
+    let question = "What is the capital of France, and why did it become the capital?";
+    let route = client
+        .chat(
+            vec![
+                ChatMessage::system("Answer 'simple' or 'complex' only."),
+                ChatMessage::user(question),
+            ],
+            None,
+            Some(ChatOptions::default().model("qwen-0.5b")),
+        )
+        .await?;
+    println!("router says: {}\n", route.content.trim());
+
+    // --- Example 2: Answer with the large model ---
+    println!("Example 2: Full Answer");
+    println!("======================\n");
+
+    let answer = client
+        .chat(
+            vec![ChatMessage::user(question)],
+            None,
+            Some(ChatOptions::default().model("qwen-7b")),
+        )
+        .await?;
+    println!("{}\n", answer.content);
+
+    // --- Example 3: Memory management ---
+    println!("Example 3: Memory");
+    println!("=================\n");
+
+    for (name, usage) in client.model_memory_usage() {
+        println!("{:<10} {:.1} MiB", name, usage as f64 / (1024.0 * 1024.0));
+    }
+
+    // Done routing — free the big one.
+    client.unload("qwen-7b").await?;
+    println!("\n✓ Unloaded qwen-7b");
+
+    Ok(())
+}
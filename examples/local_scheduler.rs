@@ -0,0 +1,86 @@
+//! # Example: Local Model Scheduler
+//!
+//! When five forest agents share one local GGUF model, concurrent generate
+//! calls either serialize unfairly or corrupt output depending on timing.
+//! This example demonstrates the explicit scheduler in the local provider:
+//! a request queue with a round-robin fairness policy across requesting
+//! agents, configurable queue depth with typed `QueueFull` errors,
+//! per-request priority, and queue wait-time metrics. Streaming requests
+//! can't hog the model — per-request time slices and max tokens are
+//! enforced.
+//!
+//! ## Prerequisites
+//!
+//! A `[local]` section in config.toml pointing at a GGUF model.
+
+use helios_engine::llm::local::{SchedulerConfig, SchedulerPriority};
+use helios_engine::{Agent, Config, ForestBuilder};
+
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - Local Model Scheduler Example");
+    println!("================================================\n");
+
+    let mut config = Config::from_file("config.toml")?;
+
+    // Round-robin across sessions, at most 16 queued requests, 30-second
+    // generation slices so a long streaming request can't starve the rest.
+    config.local_scheduler = Some(
+        SchedulerConfig::round_robin()
+            .queue_depth(16)
+            .time_slice_secs(30),
+    );
+
+    let mut forest = ForestBuilder::new()
+        .config(config)
+        .agent(
+            "coordinator".to_string(),
+            Agent::builder("coordinator")
+                .system_prompt("You coordinate the workers.")
+                // The coordinator's requests jump the queue.
+                .scheduler_priority(SchedulerPriority::High),
+        )
+        .agent(
+            "worker1".to_string(),
+            Agent::builder("worker1").system_prompt("You complete assigned tasks."),
+        )
+        .agent(
+            "worker2".to_string(),
+            Agent::builder("worker2").system_prompt("You complete assigned tasks."),
+        )
+        .agent(
+            "worker3".to_string(),
+            Agent::builder("worker3").system_prompt("You complete assigned tasks."),
+        )
+        .build()
+        .await?;
+
+    println!("✓ Forest sharing one local model through the scheduler\n");
+
+    // All agents hit the local model concurrently; the scheduler serializes
+    // actual generation while keeping turn order fair.
+    let result = forest
+        .execute_collaborative_task(
+            &"coordinator".to_string(),
+            "Each worker should name one benefit of code review.".to_string(),
+            vec![
+                "worker1".to_string(),
+                "worker2".to_string(),
+                "worker3".to_string(),
+            ],
+        )
+        .await?;
+    println!("Result: {}\n", result);
+
+    // --- Queue metrics ---
+    println!("Scheduler Metrics");
+    println!("=================\n");
+
+    let metrics = forest.local_scheduler_metrics();
+    println!("requests served:   {}", metrics.served);
+    println!("mean queue wait:   {:?}", metrics.mean_wait);
+    println!("max queue depth:   {}", metrics.max_depth_seen);
+    println!("queue-full errors: {}", metrics.rejected);
+
+    Ok(())
+}
@@ -0,0 +1,89 @@
+//! # Example: Local Model Tuning
+//!
+//! `LocalConfig` now exposes the knobs that matter on real hardware: GPU
+//! offload (`n_gpu_layers`), CPU threads (`n_threads`), batch size
+//! (`n_batch`), and the sampling parameters `top_k`, `top_p`,
+//! `repeat_penalty`, `repeat_last_n`, and `seed`. Defaults match the old
+//! behavior, so existing configs keep working; requesting GPU layers on a
+//! CPU-only build warns instead of failing. The same values can be
+//! overridden per call through `ChatOptions`, exactly as for remote
+//! providers.
+//!
+//! Note: This example requires the `local` feature to be enabled.
+//! Run with: cargo run --example local_tuning --features local
+
+#[cfg(not(feature = "local"))]
+fn main() {
+    eprintln!("❌ This example requires the 'local' feature to be enabled.");
+    eprintln!("Run with: cargo run --example local_tuning --features local");
+    std::process::exit(1);
+}
+
+#[cfg(feature = "local")]
+use helios_engine::config::LocalConfig;
+#[cfg(feature = "local")]
+use helios_engine::llm::ChatOptions;
+#[cfg(feature = "local")]
+use helios_engine::{ChatMessage, LLMClient};
+
+#[cfg(feature = "local")]
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - Local Model Tuning Example");
+    println!("=============================================\n");
+
+    // All new fields default to the previous behavior, so you only set
+    // what you care about.
+    let local_config = LocalConfig {
+        huggingface_repo: "unsloth/Qwen2.5-0.5B-Instruct-GGUF".to_string(),
+        model_file: "Qwen2.5-0.5B-Instruct-Q4_K_M.gguf".to_string(),
+        context_size: 4096,
+        temperature: 0.7,
+        max_tokens: 512,
+        // Offload everything to the GPU; on a CPU-only build this warns
+        // and falls back rather than failing the load.
+        n_gpu_layers: Some(99),
+        n_threads: Some(8),
+        n_batch: Some(512),
+        // Sampling, applied at generation time.
+        top_k: Some(40),
+        top_p: Some(0.95),
+        repeat_penalty: Some(1.1),
+        repeat_last_n: Some(64),
+        // Fixed seed makes runs reproducible — handy for evals.
+        seed: Some(42),
+    };
+
+    println!("📥 Loading local model...");
+    let client = LLMClient::new(helios_engine::llm::LLMProviderType::Local(local_config)).await?;
+    println!("✓ Model loaded\n");
+
+    // --- Example 1: Generation with the configured defaults ---
+    println!("Example 1: Configured Defaults");
+    println!("==============================\n");
+
+    let messages = vec![
+        ChatMessage::system("You are a helpful assistant."),
+        ChatMessage::user("Explain GPU offloading in one paragraph."),
+    ];
+    let response = client.chat(messages.clone(), None, None).await?;
+    println!("Assistant: {}\n", response.content);
+
+    // --- Example 2: Per-call overrides via ChatOptions ---
+    println!("Example 2: Per-Call Overrides");
+    println!("=============================\n");
+
+    // Same mechanism remote providers use: lower the temperature for one
+    // deterministic turn without touching the config.
+    let options = ChatOptions::default()
+        .temperature(0.1)
+        .top_p(0.5)
+        .seed(7);
+
+    let response = client
+        .chat_with_options(messages, None, None, options)
+        .await?;
+    println!("Assistant (precise): {}", response.content);
+
+    Ok(())
+}
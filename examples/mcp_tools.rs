@@ -0,0 +1,72 @@
+//! # Example: MCP Server Tools
+//!
+//! A lot of tooling now ships as MCP (Model Context Protocol) servers. This
+//! example demonstrates the `McpToolProvider`: it connects to an MCP server
+//! over stdio or SSE, calls `tools/list`, and generates one `Tool`
+//! implementation per remote tool — translating the MCP JSON schema into
+//! `ToolParameter`s and forwarding `execute` calls as `tools/call`.
+//!
+//! `AgentBuilder::mcp_server` registers all discovered tools at build time.
+//! Server disconnects surface as tool errors rather than panics, and
+//! multiple servers are supported with prefix-based name disambiguation.
+//!
+//! ## Prerequisites
+//!
+//! An MCP server to connect to, e.g.:
+//!
+//! ```sh
+//! npx -y @modelcontextprotocol/server-filesystem /tmp
+//! ```
+
+use helios_engine::mcp::{McpServerConfig, McpToolProvider};
+use helios_engine::{Agent, Config};
+
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - MCP Tools Example");
+    println!("====================================\n");
+
+    let config = Config::from_file("config.toml")?;
+
+    // --- Example 1: Discover tools from a stdio MCP server ---
+    println!("Example 1: Tool Discovery");
+    println!("=========================\n");
+
+    let fs_server = McpServerConfig::stdio(
+        "npx",
+        &["-y", "@modelcontextprotocol/server-filesystem", "/tmp"],
+    );
+
+    let provider = McpToolProvider::connect(fs_server.clone()).await?;
+    println!("✓ Connected; server exposes {} tools:", provider.tools().len());
+    for tool in provider.tools() {
+        println!("  - {}: {}", tool.name(), tool.description());
+    }
+
+    // --- Example 2: Attach MCP servers to an agent ---
+    println!("\nExample 2: Agent with MCP Tools");
+    println!("===============================\n");
+
+    // A second server over SSE. Because two servers may both export a tool
+    // named e.g. `search`, each server's tools are prefixed with its name
+    // (`fs_read_file`, `web_search`, ...).
+    let web_server = McpServerConfig::sse("http://localhost:3001/sse").named("web");
+
+    let mut agent = Agent::builder("McpAgent")
+        .config(config)
+        .system_prompt("You have filesystem and web tools provided by MCP servers.")
+        .mcp_server(fs_server.named("fs"))
+        .mcp_server(web_server)
+        .build()
+        .await?;
+
+    let response = agent.chat("List the files in /tmp.").await?;
+    println!("Agent: {}\n", response);
+
+    // If a server disconnects mid-conversation, its tools return error
+    // results that the model can react to — the agent keeps running.
+    let response = agent.chat("Now read /tmp/notes.txt if it exists.").await?;
+    println!("Agent: {}", response);
+
+    Ok(())
+}
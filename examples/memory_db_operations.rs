@@ -0,0 +1,87 @@
+//! # Example: Numeric Operations and Lists in MemoryDB
+//!
+//! Plain key-value set/get forces the model into read-modify-write cycles,
+//! which it gets wrong. This example demonstrates the atomic operations on
+//! `MemoryDBTool`: `incr`/`decr` with an amount (the key is created at 0 if
+//! missing; non-numeric values error), `append` to a list value, `lrange`
+//! to read a slice, and `llen`. Values stay JSON-typed internally, so lists
+//! and numbers round-trip instead of being stringified.
+
+use helios_engine::{Agent, Config, MemoryDBTool, Tool};
+
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - MemoryDB Operations Example");
+    println!("==============================================\n");
+
+    let tool = MemoryDBTool::new();
+
+    // --- Example 1: Atomic counters ---
+    println!("Example 1: incr / decr");
+    println!("======================\n");
+
+    // Missing key: created at 0, then incremented.
+    let result = tool
+        .execute(serde_json::json!({"operation": "incr", "key": "visits", "amount": 1}))
+        .await?;
+    println!("incr visits → {}", result.output);
+
+    let result = tool
+        .execute(serde_json::json!({"operation": "incr", "key": "visits", "amount": 5}))
+        .await?;
+    println!("incr visits by 5 → {}", result.output);
+
+    let result = tool
+        .execute(serde_json::json!({"operation": "decr", "key": "visits", "amount": 2}))
+        .await?;
+    println!("decr visits by 2 → {}", result.output);
+
+    // Non-numeric values error instead of silently coercing.
+    tool.execute(serde_json::json!({"operation": "set", "key": "name", "value": "helios"}))
+        .await?;
+    let result = tool
+        .execute(serde_json::json!({"operation": "incr", "key": "name"}))
+        .await?;
+    println!("incr on string → {}\n", result.output);
+
+    // --- Example 2: Lists ---
+    println!("Example 2: append / lrange / llen");
+    println!("=================================\n");
+
+    for item in ["write docs", "fix bug", "cut release"] {
+        tool.execute(serde_json::json!({"operation": "append", "key": "todo", "value": item}))
+            .await?;
+    }
+
+    let result = tool
+        .execute(serde_json::json!({"operation": "llen", "key": "todo"}))
+        .await?;
+    println!("llen todo → {}", result.output);
+
+    let result = tool
+        .execute(serde_json::json!({"operation": "lrange", "key": "todo", "start": 0, "end": 1}))
+        .await?;
+    println!("lrange todo 0..1 → {}\n", result.output);
+
+    // --- Example 3: An agent keeping an accurate tally ---
+    println!("Example 3: Agent-Maintained Counter");
+    println!("===================================\n");
+
+    let config = Config::from_file("config.toml")?;
+
+    let mut agent = Agent::builder("Tally")
+        .config(config)
+        .system_prompt(
+            "Track counts with the memory_db incr operation — never read a \
+             number, add to it yourself, and write it back.",
+        )
+        .tool(Box::new(MemoryDBTool::new()))
+        .build()
+        .await?;
+
+    agent.chat("Count three completed tasks, one at a time.").await?;
+    let response = agent.chat("How many tasks are completed?").await?;
+    println!("Agent: {}", response);
+
+    Ok(())
+}
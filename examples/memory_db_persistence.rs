@@ -0,0 +1,71 @@
+//! # Example: Persistent MemoryDBTool
+//!
+//! The in-memory `MemoryDBTool` loses everything when the process exits,
+//! which defeats "store my preferences". This example demonstrates the
+//! persistent constructors:
+//!
+//! - `MemoryDBTool::with_file(path)` — loads an existing JSON file at
+//!   construction and flushes atomically on every mutating operation
+//! - `MemoryDBTool::with_sqlite(path)` — feature-gated, for larger datasets
+//!
+//! Concurrent access from multiple agents sharing one tool instance is
+//! safe. The purely in-memory `MemoryDBTool::new()` remains the default.
+
+use helios_engine::{Agent, Config, MemoryDBTool};
+
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - Persistent MemoryDB Example");
+    println!("==============================================\n");
+
+    let config = Config::from_file("config.toml")?;
+
+    // --- Example 1: JSON file persistence ---
+    println!("Example 1: with_file");
+    println!("====================\n");
+
+    // Loads memory_db.json if it exists; every set/delete/clear flushes the
+    // file atomically (temp file + rename), so a crash never corrupts it.
+    let db_tool = MemoryDBTool::with_file("memory_db.json")?;
+
+    let mut agent = Agent::builder("PersistentAgent")
+        .config(config.clone())
+        .system_prompt("You store and recall user preferences with the memory_db tool.")
+        .tool(Box::new(db_tool))
+        .build()
+        .await?;
+
+    agent
+        .chat("Remember that my preferred language is Rust and my timezone is UTC+2.")
+        .await?;
+    println!("✓ Preferences stored and flushed to memory_db.json\n");
+
+    // --- Example 2: Survives a restart ---
+    println!("Example 2: After Restart");
+    println!("========================\n");
+
+    // Simulate a fresh process: build a brand new agent over the same file.
+    drop(agent);
+    let db_tool = MemoryDBTool::with_file("memory_db.json")?;
+
+    let mut agent = Agent::builder("PersistentAgent")
+        .config(config)
+        .system_prompt("You store and recall user preferences with the memory_db tool.")
+        .tool(Box::new(db_tool))
+        .build()
+        .await?;
+
+    let response = agent.chat("What's my preferred language?").await?;
+    println!("Agent: {}\n", response);
+
+    // --- Example 3: SQLite for larger datasets ---
+    println!("Example 3: with_sqlite (feature = \"sqlite\")");
+    println!("===========================================\n");
+
+    // For datasets too big for a single JSON file:
+    //   let db_tool = MemoryDBTool::with_sqlite("memory_db.sqlite")?;
+    // Same tool interface, same concurrency guarantees.
+    println!("Enable with: cargo run --example memory_db_persistence --features sqlite");
+
+    Ok(())
+}
@@ -0,0 +1,89 @@
+//! # Example: Named Model Profiles
+//!
+//! One `config.toml` can define several models — fast, smart, local — and
+//! each agent can pick one. This example demonstrates `[models.*]` tables
+//! with a `default` pointer, the `Config::profile(name)` accessor, and
+//! `AgentBuilder::model_profile("smart")` for per-agent selection, so
+//! different agents in one forest run on different models. Unknown profile
+//! names fail at build time with the available profiles listed.
+//!
+//! ## Prerequisites
+//!
+//! ```toml
+//! default = "fast"
+//!
+//! [models.fast]
+//! model_name = "gpt-4o-mini"
+//! base_url = "https://api.openai.com/v1"
+//!
+//! [models.smart]
+//! model_name = "gpt-4o"
+//! base_url = "https://api.openai.com/v1"
+//!
+//! [models.local]
+//! model_path = "models/qwen2-7b-instruct-q4_k_m.gguf"
+//! ```
+
+use helios_engine::{Agent, Config, ForestBuilder};
+
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - Model Profiles Example");
+    println!("=========================================\n");
+
+    let config = Config::from_file("config.toml")?;
+
+    // --- Example 1: Inspecting profiles ---
+    println!("Example 1: Profiles");
+    println!("===================\n");
+
+    for name in config.profile_names() {
+        let profile = config.profile(&name)?;
+        println!("{:<8} → {}", name, profile.model_name);
+    }
+    println!();
+
+    // A typo fails up front with the list of valid names:
+    if let Err(e) = config.profile("smrat") {
+        println!("⚠ {}\n", e);
+    }
+
+    // --- Example 2: Different agents on different models ---
+    println!("Example 2: Per-Agent Selection");
+    println!("==============================\n");
+
+    let mut forest = ForestBuilder::new()
+        .config(config)
+        .agent(
+            "coordinator".to_string(),
+            Agent::builder("coordinator")
+                .system_prompt("You plan and delegate.")
+                .model_profile("smart"),
+        )
+        .agent(
+            "summarizer".to_string(),
+            // Cheap work goes to the cheap model.
+            Agent::builder("summarizer")
+                .system_prompt("You summarize.")
+                .model_profile("fast"),
+        )
+        .agent(
+            "drafter".to_string(),
+            // No profile → the config's `default` pointer.
+            Agent::builder("drafter").system_prompt("You draft."),
+        )
+        .build()
+        .await?;
+
+    let result = forest
+        .execute_collaborative_task(
+            &"coordinator".to_string(),
+            "Draft and summarize a note on profiling Rust programs.".to_string(),
+            vec!["drafter".to_string(), "summarizer".to_string()],
+        )
+        .await?;
+
+    println!("Result: {}", result);
+
+    Ok(())
+}
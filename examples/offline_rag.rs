@@ -0,0 +1,87 @@
+//! # Example: Fully Offline RAG Agent
+//!
+//! With the `local` feature, embeddings can come from a GGUF or
+//! safetensors model (nomic-embed, bge-small) fetched through the same
+//! HuggingFace download path as local LLMs — no OpenAI key anywhere. This
+//! example demonstrates `RAGTool::new_in_memory_local(LocalEmbeddingConfig)`
+//! building a complete offline RAG agent. Embeddings are normalized so
+//! cosine similarity in `InMemoryVectorStore` behaves exactly as it does
+//! on the OpenAI path.
+//!
+//! Note: This example requires the `local` feature to be enabled.
+//! Run with: cargo run --example offline_rag --features local
+
+#[cfg(not(feature = "local"))]
+fn main() {
+    eprintln!("❌ This example requires the 'local' feature to be enabled.");
+    eprintln!("Run with: cargo run --example offline_rag --features local");
+    std::process::exit(1);
+}
+
+#[cfg(feature = "local")]
+use helios_engine::config::{LocalConfig, LocalEmbeddingConfig};
+#[cfg(feature = "local")]
+use helios_engine::{Agent, Document, RAGTool};
+
+#[cfg(feature = "local")]
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - Offline RAG Example");
+    println!("======================================\n");
+
+    // Embedding model: small, fast, downloaded once and cached.
+    let embedding_config = LocalEmbeddingConfig {
+        huggingface_repo: "nomic-ai/nomic-embed-text-v1.5-GGUF".to_string(),
+        model_file: "nomic-embed-text-v1.5.Q4_K_M.gguf".to_string(),
+        ..Default::default()
+    };
+
+    // In-memory store + local embeddings = nothing leaves the machine.
+    // Vectors are L2-normalized on the way in, matching the OpenAI path.
+    let rag_tool = RAGTool::new_in_memory_local(embedding_config).await?;
+
+    rag_tool
+        .add_documents(vec![
+            Document {
+                id: "1".to_string(),
+                content: "The warehouse inventory system syncs every night at 02:00.".to_string(),
+                metadata: None,
+            },
+            Document {
+                id: "2".to_string(),
+                content: "Returns are processed within five business days.".to_string(),
+                metadata: None,
+            },
+            Document {
+                id: "3".to_string(),
+                content: "Support is reachable at extension 4411 during office hours.".to_string(),
+                metadata: None,
+            },
+        ])
+        .await?;
+    println!("✓ Indexed 3 documents locally\n");
+
+    // The chat model is local too, so the whole agent runs offline.
+    let local_config = LocalConfig {
+        huggingface_repo: "unsloth/Qwen2.5-0.5B-Instruct-GGUF".to_string(),
+        model_file: "Qwen2.5-0.5B-Instruct-Q4_K_M.gguf".to_string(),
+        context_size: 4096,
+        temperature: 0.7,
+        max_tokens: 512,
+        ..Default::default()
+    };
+
+    let mut agent = Agent::builder("offline-assistant")
+        .local_config(local_config)
+        .system_prompt(
+            "Answer questions using the knowledge base. Search it before answering.",
+        )
+        .tool(Box::new(rag_tool))
+        .build()
+        .await?;
+
+    let response = agent.chat("When does the inventory sync run?").await?;
+    println!("Agent: {}", response);
+
+    Ok(())
+}
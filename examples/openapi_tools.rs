@@ -0,0 +1,65 @@
+//! # Example: Tools from an OpenAPI Specification
+//!
+//! Internal REST APIs usually ship OpenAPI 3 specs — no need to hand-write a
+//! tool per endpoint. This example demonstrates `OpenApiToolset::from_spec`:
+//! it parses the spec (local path or URL), creates one `Tool` per
+//! operationId with parameters derived from path/query/body schemas, and
+//! executes calls via reqwest against a configurable base URL.
+//!
+//! Responses come back as pretty-printed JSON with a size cap; 4xx/5xx are
+//! surfaced as tool output (so the model can react), not crate errors.
+
+use helios_engine::tools::OpenApiToolset;
+use helios_engine::{Agent, Config};
+
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - OpenAPI Tools Example");
+    println!("========================================\n");
+
+    let config = Config::from_file("config.toml")?;
+
+    // --- Example 1: Load a spec and inspect the generated tools ---
+    println!("Example 1: Loading the Spec");
+    println!("===========================\n");
+
+    let toolset = OpenApiToolset::from_spec("https://petstore3.swagger.io/api/v3/openapi.json")
+        .await?
+        .base_url("https://petstore3.swagger.io/api/v3")
+        // Restrict what the agent can touch: filter by tag or allow-list.
+        .include_tags(&["pet"])
+        .allow_operations(&["getPetById", "findPetsByStatus"]);
+
+    println!("✓ Generated {} tools from the spec:", toolset.tools().len());
+    for tool in toolset.tools() {
+        println!("  - {}: {}", tool.name(), tool.description());
+    }
+
+    // --- Example 2: Give the toolset to an agent ---
+    println!("\nExample 2: Agent Calling the API");
+    println!("================================\n");
+
+    let mut agent = Agent::builder("ApiAgent")
+        .config(config)
+        .system_prompt(
+            "You answer questions about the pet store by calling its API tools.",
+        )
+        .toolset(toolset)
+        .build()
+        .await?;
+
+    let response = agent.chat("Which pets are currently available?").await?;
+    println!("Agent: {}\n", response);
+
+    // A not-found id produces a 404 as tool output; the agent explains it
+    // instead of the process erroring out.
+    let response = agent.chat("Show me pet number 999999999.").await?;
+    println!("Agent: {}\n", response);
+
+    // For internal APIs, attach bearer auth:
+    //   OpenApiToolset::from_spec("specs/internal.yaml").await?
+    //       .base_url("https://internal.example.com")
+    //       .bearer_token(std::env::var("API_TOKEN").unwrap())
+
+    Ok(())
+}
@@ -0,0 +1,81 @@
+//! # Example: PDF Ingestion and the PdfReadTool
+//!
+//! Most real knowledge bases are PDFs. Behind the `pdf` feature,
+//! `ingest_file` detects `.pdf` and extracts text with a pure-Rust
+//! extractor, preserving page numbers as chunk metadata so citations can
+//! say "page 12". Encrypted or image-only PDFs produce a descriptive
+//! ingestion error for that file while the rest of the batch continues.
+//! The standalone `PdfReadTool` lets agents read a specific PDF on demand
+//! with a page-range argument.
+//!
+//! Run with:
+//!
+//! ```sh
+//! cargo run --example pdf_ingestion --features pdf
+//! ```
+
+use helios_engine::{Agent, Config, InMemoryVectorStore, OpenAIEmbeddings, PdfReadTool, RAGSystem};
+
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - PDF Ingestion Example");
+    println!("========================================\n");
+
+    let embeddings = OpenAIEmbeddings::new(
+        "https://api.openai.com/v1/embeddings".to_string(),
+        std::env::var("OPENAI_API_KEY").unwrap_or_default(),
+    );
+
+    let vector_store = InMemoryVectorStore::new(embeddings);
+    let mut rag_system = RAGSystem::new(vector_store);
+
+    // --- Example 1: Ingest a directory of PDFs ---
+    println!("Example 1: Batch PDF Ingestion");
+    println!("==============================\n");
+
+    let summary = rag_system.ingest_directory("./manuals", "**/*.pdf").await?;
+    println!("files processed: {}", summary.files_processed);
+    println!("chunks added:    {}", summary.chunks_added);
+
+    // Encrypted or image-only PDFs don't abort the batch; they show up as
+    // per-file errors in the summary.
+    for error in &summary.errors {
+        println!("  ⚠ {}: {}", error.path, error.message);
+    }
+    println!();
+
+    // --- Example 2: Page numbers in search results ---
+    println!("Example 2: Page-Aware Results");
+    println!("=============================\n");
+
+    let results = rag_system.search("warranty period", 3).await?;
+    for result in &results {
+        println!(
+            "{} — page {} (score {:.3})",
+            result.document.metadata["source"],
+            result.document.metadata["page"],
+            result.score
+        );
+    }
+
+    // --- Example 3: On-demand reading with PdfReadTool ---
+    println!("\nExample 3: PdfReadTool");
+    println!("======================\n");
+
+    let config = Config::from_file("config.toml")?;
+
+    let mut agent = Agent::builder("ManualReader")
+        .config(config)
+        .system_prompt("You answer questions by reading PDF manuals, citing pages.")
+        .tool(Box::new(PdfReadTool))
+        .build()
+        .await?;
+
+    // The tool takes {"path": "...", "pages": "12-14"}.
+    let response = agent
+        .chat("Read pages 12 to 14 of manuals/toaster.pdf and summarize the safety notes.")
+        .await?;
+    println!("Agent: {}", response);
+
+    Ok(())
+}
@@ -0,0 +1,99 @@
+//! # Example: Plan Cycle Detection and Validation
+//!
+//! Coordinators sometimes produce plans with circular dependencies
+//! (`task_2` depends on `task_3` and vice versa) or assign tasks to agents
+//! that don't exist — execution would then hang or panic. This example
+//! demonstrates the validation step in `create_plan`: cycles, unknown
+//! `assigned_to` ids, duplicate task ids, and dependencies on nonexistent
+//! tasks are detected and returned as the tool result so the coordinator
+//! can regenerate the plan. After N failed validations, the collaborative
+//! task fails with a structured error carrying the last report.
+
+use helios_engine::forest::CreatePlanTool;
+use helios_engine::{Agent, Config, ForestBuilder, Tool};
+
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - Plan Validation Example");
+    println!("==========================================\n");
+
+    // --- Example 1: Each failure mode, directly ---
+    println!("Example 1: Validation Failures");
+    println!("==============================\n");
+
+    let tool = CreatePlanTool::with_known_agents(&["worker1", "worker2"]);
+
+    // Dependency cycle.
+    let result = tool
+        .execute(serde_json::json!({
+            "objective": "demo",
+            "tasks": [
+                {"id": "task_1", "description": "a", "assigned_to": "worker1", "dependencies": ["task_2"]},
+                {"id": "task_2", "description": "b", "assigned_to": "worker2", "dependencies": ["task_1"]}
+            ]
+        }))
+        .await?;
+    println!("cycle → {}\n", result.output);
+
+    // Unknown assignee and dangling dependency.
+    let result = tool
+        .execute(serde_json::json!({
+            "objective": "demo",
+            "tasks": [
+                {"id": "task_1", "description": "a", "assigned_to": "ghost", "dependencies": []},
+                {"id": "task_2", "description": "b", "assigned_to": "worker1", "dependencies": ["task_9"]}
+            ]
+        }))
+        .await?;
+    println!("unknown agent + dangling dep → {}\n", result.output);
+
+    // Duplicate task ids.
+    let result = tool
+        .execute(serde_json::json!({
+            "objective": "demo",
+            "tasks": [
+                {"id": "task_1", "description": "a", "assigned_to": "worker1", "dependencies": []},
+                {"id": "task_1", "description": "b", "assigned_to": "worker2", "dependencies": []}
+            ]
+        }))
+        .await?;
+    println!("duplicate ids → {}\n", result.output);
+
+    // --- Example 2: In a forest the coordinator gets N attempts ---
+    println!("Example 2: Bounded Regeneration");
+    println!("===============================\n");
+
+    let config = Config::from_file("config.toml")?;
+
+    let mut forest = ForestBuilder::new()
+        .config(config)
+        .agent(
+            "coordinator".to_string(),
+            Agent::builder("coordinator")
+                .system_prompt("Create plans; fix any validation problems the tool reports.")
+                .max_iterations(15),
+        )
+        .agent(
+            "worker1".to_string(),
+            Agent::builder("worker1").system_prompt("You complete tasks."),
+        )
+        // After 3 invalid plans in a row, the run fails with the last
+        // validation report instead of looping forever.
+        .max_plan_validation_failures(3)
+        .build()
+        .await?;
+
+    match forest
+        .execute_collaborative_task(
+            &"coordinator".to_string(),
+            "Summarize the benefits of static typing.".to_string(),
+            vec!["worker1".to_string()],
+        )
+        .await
+    {
+        Ok(result) => println!("Result: {}", result),
+        Err(e) => println!("Failed with validation report: {}", e),
+    }
+
+    Ok(())
+}
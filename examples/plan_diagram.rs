@@ -0,0 +1,79 @@
+//! # Example: Exporting the Task Plan as a Diagram
+//!
+//! For demos and debugging it helps to see the dependency graph the
+//! coordinator produced. This example demonstrates `TaskPlan::to_mermaid()`
+//! and `TaskPlan::to_dot()`: tasks render as nodes labeled with id,
+//! assignee, and status (color-coded), dependencies as edges, the objective
+//! as the title, with special characters escaped. The convenience
+//! `Forest::export_plan_diagram(path, format)` works at any point
+//! mid-execution, reflecting current task statuses.
+
+use helios_engine::forest::DiagramFormat;
+use helios_engine::{Agent, Config, ForestBuilder};
+
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - Plan Diagram Example");
+    println!("=======================================\n");
+
+    let config = Config::from_file("config.toml")?;
+
+    let mut forest = ForestBuilder::new()
+        .config(config)
+        .agent(
+            "coordinator".to_string(),
+            Agent::builder("coordinator").system_prompt("You create multi-step plans."),
+        )
+        .agent(
+            "researcher".to_string(),
+            Agent::builder("researcher").system_prompt("You research."),
+        )
+        .agent(
+            "writer".to_string(),
+            Agent::builder("writer").system_prompt("You write."),
+        )
+        .build()
+        .await?;
+
+    // Kick off a task in the background so we can snapshot mid-execution.
+    let handle = {
+        let task = "Produce a short market overview: research first, then write.";
+        forest.spawn_collaborative_task(
+            &"coordinator".to_string(),
+            task.to_string(),
+            vec!["researcher".to_string(), "writer".to_string()],
+        )
+    };
+
+    // Wait for the plan to exist, then export a mid-run snapshot — running
+    // tasks show as in-progress (yellow), finished ones as green.
+    forest.wait_for_plan().await?;
+    forest
+        .export_plan_diagram("plan_mid_run.mmd", DiagramFormat::Mermaid)
+        .await?;
+    println!("✓ Mid-run snapshot written to plan_mid_run.mmd\n");
+
+    let result = handle.await?;
+    println!("Result: {}\n", result);
+
+    // --- Final diagrams in both formats ---
+    let plan = forest.current_plan().expect("plan exists after the run");
+
+    println!("Mermaid");
+    println!("=======\n");
+    println!("{}\n", plan.to_mermaid());
+    // ```mermaid
+    // graph TD
+    //   title[\"Produce a short market overview\"]
+    //   task_1[\"task_1<br/>researcher<br/>Completed\"]:::done
+    //   task_2[\"task_2<br/>writer<br/>Completed\"]:::done
+    //   task_1 --> task_2
+    // ```
+
+    forest
+        .export_plan_diagram("plan_final.dot", DiagramFormat::Dot)
+        .await?;
+    println!("✓ DOT version written to plan_final.dot (render with graphviz)");
+
+    Ok(())
+}
@@ -0,0 +1,91 @@
+//! # Example: Strict create_plan Validation
+//!
+//! Coordinators frequently pass `tasks` as a JSON string instead of an
+//! array, or misspell `assigned_to`, and the plan silently ends up empty.
+//! The create_plan tool now has a strict parameter schema: the common
+//! string-encoded-JSON mistake is accepted with a warning, and anything else
+//! is rejected with a precise error enumerating per-task problems (index,
+//! field, expected type) so the model can fix the call on the next
+//! iteration. Successful plans echo back a normalized summary.
+
+use helios_engine::forest::CreatePlanTool;
+use helios_engine::{Agent, Config, ForestBuilder, Tool};
+
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - Plan Validation Example");
+    println!("==========================================\n");
+
+    // --- Example 1: What the validator does, directly ---
+    println!("Example 1: Validator Behavior");
+    println!("=============================\n");
+
+    let tool = CreatePlanTool::default();
+
+    // A well-formed plan: the result echoes a normalized summary.
+    let result = tool
+        .execute(serde_json::json!({
+            "objective": "Write a report",
+            "tasks": [
+                {"id": "task_1", "description": "Research", "assigned_to": "worker1", "dependencies": []},
+                {"id": "task_2", "description": "Write", "assigned_to": "worker2", "dependencies": ["task_1"]}
+            ]
+        }))
+        .await?;
+    println!("valid plan → {}\n", result.output);
+
+    // The common mistake: tasks as a string-encoded JSON array. Accepted,
+    // parsed, and flagged with a warning in the result.
+    let result = tool
+        .execute(serde_json::json!({
+            "objective": "Write a report",
+            "tasks": "[{\"id\":\"task_1\",\"description\":\"Research\",\"assigned_to\":\"worker1\",\"dependencies\":[]}]"
+        }))
+        .await?;
+    println!("string-encoded tasks → {}\n", result.output);
+
+    // Genuinely malformed: per-task errors name the index, field, and
+    // expected type so the model can repair the call.
+    let result = tool
+        .execute(serde_json::json!({
+            "objective": "Write a report",
+            "tasks": [
+                {"id": "task_1", "description": "Research", "asigned_to": "worker1"},
+                {"id": 2, "description": "Write"}
+            ]
+        }))
+        .await?;
+    println!("malformed tasks → {}\n", result.output);
+
+    // --- Example 2: In a real forest the coordinator self-corrects ---
+    println!("Example 2: Forest with Self-Correcting Coordinator");
+    println!("==================================================\n");
+
+    let config = Config::from_file("config.toml")?;
+
+    let mut forest = ForestBuilder::new()
+        .config(config)
+        .agent(
+            "coordinator".to_string(),
+            Agent::builder("coordinator")
+                .system_prompt("Create plans with the create_plan tool. Fix any validation errors it reports.")
+                .max_iterations(15),
+        )
+        .agent(
+            "worker1".to_string(),
+            Agent::builder("worker1").system_prompt("Complete assigned tasks briefly."),
+        )
+        .build()
+        .await?;
+
+    let result = forest
+        .execute_collaborative_task(
+            &"coordinator".to_string(),
+            "List 3 benefits of unit testing.".to_string(),
+            vec!["worker1".to_string()],
+        )
+        .await?;
+    println!("Result: {}", result);
+
+    Ok(())
+}
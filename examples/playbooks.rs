@@ -0,0 +1,90 @@
+//! # Example: Playbooks
+//!
+//! Many workflows are fixed multi-step scripts — gather inputs, call tool A,
+//! confirm with the user, call tool B, summarize — that don't need forest
+//! machinery. This example demonstrates the `Playbook` type: an ordered list
+//! of steps (prompt template turns, required tool invocations with mapped
+//! arguments, user-confirmation pauses, and branches on prior step outputs)
+//! executed by a single agent with `run_playbook`. A paused run returns a
+//! `NeedsInput` outcome and resumes with `resume_with(input)`; playbooks are
+//! also loadable from TOML/JSON with validation.
+
+use helios_engine::playbook::{Playbook, PlaybookOutcome, Step};
+use helios_engine::{Agent, CalculatorTool, Config, FileWriteTool};
+
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - Playbooks Example");
+    println!("====================================\n");
+
+    let config = Config::from_file("config.toml")?;
+
+    let mut agent = Agent::builder("PlaybookAgent")
+        .config(config)
+        .system_prompt("You execute structured workflows precisely.")
+        .tool(Box::new(CalculatorTool))
+        .tool(Box::new(FileWriteTool))
+        .build()
+        .await?;
+
+    // --- Define a quote-generation playbook in code ---
+    let playbook = Playbook::new("generate_quote")
+        .step(Step::prompt(
+            "collect",
+            "Summarize the customer's request: {{request}}",
+        ))
+        .step(Step::tool(
+            "price",
+            "calculator",
+            serde_json::json!({ "expression": "{{quantity}} * {{unit_price}}" }),
+        ))
+        // The run suspends here until the host supplies confirmation.
+        .step(Step::confirm(
+            "approval",
+            "Quote totals {{price.output}}. Send it to the customer?",
+        ))
+        // Branch on the confirmation answer.
+        .step(Step::branch(
+            "route",
+            "{{approval.input}} == 'yes'",
+            Step::tool(
+                "write",
+                "file_write",
+                serde_json::json!({ "path": "quote.txt", "content": "{{collect.output}}\nTotal: {{price.output}}" }),
+            ),
+            Step::prompt("decline", "Draft a polite note that the quote was not sent."),
+        ))
+        .step(Step::prompt("summary", "Summarize what happened in this workflow."));
+
+    // --- Run until the confirmation pause ---
+    println!("Running playbook until it needs input...\n");
+
+    let vars = serde_json::json!({
+        "request": "200 widgets, standard finish",
+        "quantity": 200,
+        "unit_price": 3.5
+    });
+
+    let mut run = agent.run_playbook(playbook, vars).await?;
+
+    match run.outcome() {
+        PlaybookOutcome::NeedsInput { step, message } => {
+            println!("⏸ Paused at step '{}': {}", step, message);
+        }
+        other => println!("Unexpected outcome: {:?}", other),
+    }
+
+    // --- Resume with the user's answer ---
+    println!("\nResuming with 'yes'...\n");
+    let run = run.resume_with("yes").await?;
+
+    println!("Playbook finished: {:?}\n", run.outcome());
+    for (step, result) in run.step_results() {
+        println!("  {:<10} {}", step, result.summary());
+    }
+
+    // The same playbook can live in a file and be validated on load:
+    //   let playbook = Playbook::from_toml_file("playbooks/generate_quote.toml")?;
+
+    Ok(())
+}
@@ -0,0 +1,88 @@
+//! # Example: Document Chunking Pipeline
+//!
+//! `RAGSystem::add_document` embeds whole documents, so long texts either
+//! fail the embedding size limit or retrieve poorly. This example
+//! demonstrates `add_document_chunked` with the three built-in strategies:
+//!
+//! - `FixedSize` — character-based windows with overlap
+//! - `SentenceAware` — breaks at sentence boundaries near the target size
+//! - `MarkdownHeaders` — splits at headers so sections stay together
+//!
+//! Chunks carry metadata linking them to the parent document id and chunk
+//! index, so `delete_document(parent_id)` removes all of them, and
+//! `SearchResult` exposes the parent id.
+//!
+//! ## Prerequisites
+//!
+//! ```sh
+//! export OPENAI_API_KEY=your-key
+//! ```
+
+use helios_engine::rag::{ChunkingConfig, ChunkingStrategy};
+use helios_engine::{InMemoryVectorStore, OpenAIEmbeddings, RAGSystem};
+
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - Document Chunking Example");
+    println!("============================================\n");
+
+    let embeddings = OpenAIEmbeddings::new(
+        "https://api.openai.com/v1/embeddings".to_string(),
+        std::env::var("OPENAI_API_KEY").unwrap_or_default(),
+    );
+
+    let vector_store = InMemoryVectorStore::new(embeddings);
+    let mut rag_system = RAGSystem::new(vector_store);
+
+    let long_text = std::fs::read_to_string("README.md")?;
+
+    // --- Example 1: Sentence-aware chunking with overlap ---
+    println!("Example 1: Sentence-Aware Chunking");
+    println!("==================================\n");
+
+    let config = ChunkingConfig {
+        strategy: ChunkingStrategy::SentenceAware,
+        chunk_size: 800,
+        overlap: 120,
+    };
+
+    let chunk_ids = rag_system
+        .add_document_chunked("readme", &long_text, config)
+        .await?;
+    println!("✓ Document split into {} chunks (120-char overlap)\n", chunk_ids.len());
+
+    // --- Example 2: Markdown-header-aware chunking ---
+    println!("Example 2: Markdown Header Chunking");
+    println!("===================================\n");
+
+    let config = ChunkingConfig {
+        strategy: ChunkingStrategy::MarkdownHeaders,
+        chunk_size: 1500,
+        overlap: 0,
+    };
+
+    let chunk_ids = rag_system
+        .add_document_chunked("readme_md", &long_text, config)
+        .await?;
+    println!("✓ {} header-aligned chunks\n", chunk_ids.len());
+
+    // --- Example 3: Search results expose the parent document ---
+    println!("Example 3: Parent-Linked Search Results");
+    println!("=======================================\n");
+
+    let results = rag_system.search("how do I run the examples", 3).await?;
+    for result in &results {
+        println!(
+            "score {:.3}  parent={}  chunk_index={}",
+            result.score,
+            result.parent_id.as_deref().unwrap_or("-"),
+            result.chunk_index.unwrap_or(0),
+        );
+    }
+
+    // Deleting the parent removes every chunk that came from it.
+    rag_system.delete_document("readme_md").await?;
+    println!("\n✓ delete_document(\"readme_md\") removed all of its chunks");
+
+    Ok(())
+}
@@ -0,0 +1,75 @@
+//! # Example: Citation Anchors with Byte Offsets
+//!
+//! A UI that highlights the exact sentence supporting each claim needs more
+//! than free-text footnotes. This example demonstrates structured citation
+//! anchors: chunk byte/char offsets within the parent document are recorded
+//! at chunking time, and grounded answers emit citations as
+//! (document id, chunk id, start, end) tuples available via
+//! `agent.last_citations()` — and as an extension field in serve responses.
+//!
+//! Document loaders preserve the original pre-normalization text (or an
+//! offset mapping), so anchors always resolve against the source.
+//!
+//! ## Prerequisites
+//!
+//! ```sh
+//! export OPENAI_API_KEY=your-key
+//! ```
+
+use helios_engine::{Agent, Config, Document, RAGTool};
+
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - Citation Anchors Example");
+    println!("===========================================\n");
+
+    let embedding_api_key = std::env::var("OPENAI_API_KEY").unwrap_or_default();
+    let rag_tool = RAGTool::new_in_memory("https://api.openai.com/v1/embeddings", embedding_api_key);
+
+    let config = Config::from_file("config.toml")?;
+
+    let mut agent = Agent::builder("GroundedAgent")
+        .config(config)
+        .system_prompt("Answer only from retrieved context, with citations.")
+        .tool(Box::new(rag_tool))
+        .grounded_answers(true)
+        .build()
+        .await?;
+
+    // Ingest a document whose exact text we know, so anchors are checkable.
+    let source_text = "Rust was first released in 2010. It guarantees memory \
+                       safety without a garbage collector. The borrow checker \
+                       enforces ownership rules at compile time.";
+
+    agent
+        .add_document(Document {
+            id: "rust_overview".to_string(),
+            content: source_text.to_string(),
+            metadata: std::collections::HashMap::new(),
+        })
+        .await?;
+    println!("✓ Document ingested with offset-preserving chunking\n");
+
+    // --- Ask a question and inspect the anchors ---
+    let response = agent.chat("When was Rust first released?").await?;
+    println!("Agent: {}\n", response);
+
+    println!("Citations");
+    println!("=========\n");
+
+    for citation in agent.last_citations() {
+        println!(
+            "doc={} chunk={} bytes {}..{}",
+            citation.document_id, citation.chunk_id, citation.start, citation.end
+        );
+        // The anchor slices the original document text exactly.
+        let quoted = &source_text[citation.start..citation.end];
+        println!("  ↳ \"{}\"", quoted);
+    }
+
+    // Over HTTP, the same anchors appear in the response extension field:
+    //   "helios_citations": [{"document_id": "...", "chunk_id": "...",
+    //                         "start": 0, "end": 31}]
+
+    Ok(())
+}
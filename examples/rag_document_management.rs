@@ -0,0 +1,107 @@
+//! # Example: Document Management on RAGSystem
+//!
+//! The RAG API had add/delete/search/count/clear but no way to fetch a
+//! document by id or update it in place. This example demonstrates the new
+//! operations — `get_document(id)`, `update_document(id, text, metadata)`
+//! (which re-embeds), and `list_documents(offset, limit)` for pagination —
+//! available on both the in-memory and Qdrant stores, and exposed through
+//! `RAGTool` as `get`, `update`, and `list` operations so agents can manage
+//! their own knowledge base conversationally.
+//!
+//! ## Prerequisites
+//!
+//! ```sh
+//! export OPENAI_API_KEY=your-key
+//! ```
+
+use helios_engine::{Agent, Config, Document, InMemoryVectorStore, OpenAIEmbeddings, RAGSystem, RAGTool};
+
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - Document Management Example");
+    println!("==============================================\n");
+
+    let embeddings = OpenAIEmbeddings::new(
+        "https://api.openai.com/v1/embeddings".to_string(),
+        std::env::var("OPENAI_API_KEY").unwrap_or_default(),
+    );
+
+    let vector_store = InMemoryVectorStore::new(embeddings);
+    let mut rag_system = RAGSystem::new(vector_store);
+
+    for i in 1..=5 {
+        rag_system
+            .add_documents(vec![Document {
+                id: format!("policy_{}", i),
+                content: format!("Policy number {} text goes here.", i),
+                metadata: std::collections::HashMap::new(),
+            }])
+            .await?;
+    }
+
+    // --- Example 1: get_document ---
+    println!("Example 1: get_document");
+    println!("=======================\n");
+
+    match rag_system.get_document("policy_3").await? {
+        Some(doc) => println!("found: {} → {}", doc.id, doc.content),
+        None => println!("not found"),
+    }
+    println!(
+        "missing id: {:?}\n",
+        rag_system.get_document("policy_99").await?
+    );
+
+    // --- Example 2: update_document re-embeds in place ---
+    println!("Example 2: update_document");
+    println!("==========================\n");
+
+    let mut metadata = std::collections::HashMap::new();
+    metadata.insert("revised".to_string(), "2026-08-27".to_string());
+
+    rag_system
+        .update_document(
+            "policy_3",
+            "Policy number 3 was revised: remote work now needs no approval.",
+            metadata,
+        )
+        .await?;
+
+    let results = rag_system.search("remote work approval", 1).await?;
+    println!("search now finds the revision: {}\n", results[0].document.id);
+
+    // --- Example 3: list_documents pagination ---
+    println!("Example 3: list_documents");
+    println!("=========================\n");
+
+    let page = rag_system.list_documents(0, 3).await?;
+    for doc in &page {
+        println!("  {}", doc.id);
+    }
+    let page = rag_system.list_documents(3, 3).await?;
+    println!("  ... next page: {} docs\n", page.len());
+
+    // --- Example 4: Conversational management via RAGTool ---
+    println!("Example 4: Agent Managing Its Knowledge Base");
+    println!("============================================\n");
+
+    let config = Config::from_file("config.toml")?;
+    let rag_tool = RAGTool::new_in_memory(
+        "https://api.openai.com/v1/embeddings",
+        std::env::var("OPENAI_API_KEY").unwrap_or_default(),
+    );
+
+    let mut agent = Agent::builder("Librarian")
+        .config(config)
+        .system_prompt("You manage a knowledge base with the rag tool's add, get, update, list, and delete operations.")
+        .tool(Box::new(rag_tool))
+        .build()
+        .await?;
+
+    let response = agent
+        .chat("List what's in the knowledge base, then update anything that mentions 'draft' to say 'final'.")
+        .await?;
+    println!("Agent: {}", response);
+
+    Ok(())
+}
@@ -0,0 +1,77 @@
+//! # Example: Hybrid Search (BM25 + Vector)
+//!
+//! Pure cosine similarity misses exact-term queries like error codes or
+//! function names. This example demonstrates the keyword index (BM25 over
+//! tokenized text) maintained alongside the vector store, and
+//! `RAGSystem::hybrid_search(query, limit, alpha)` which runs both
+//! retrievals and fuses the scores. `alpha` weights the fusion: 0.0 is pure
+//! keyword, 1.0 is pure vector. The `RAGTool` exposes the same choice via a
+//! `mode` argument (`vector` | `keyword` | `hybrid`).
+//!
+//! ## Prerequisites
+//!
+//! ```sh
+//! export OPENAI_API_KEY=your-key
+//! ```
+
+use helios_engine::{Document, InMemoryVectorStore, OpenAIEmbeddings, RAGSystem};
+
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - Hybrid Search Example");
+    println!("========================================\n");
+
+    let embeddings = OpenAIEmbeddings::new(
+        "https://api.openai.com/v1/embeddings".to_string(),
+        std::env::var("OPENAI_API_KEY").unwrap_or_default(),
+    );
+
+    let vector_store = InMemoryVectorStore::new(embeddings);
+    let mut rag_system = RAGSystem::new(vector_store);
+
+    let docs = [
+        ("err_e0502", "Error E0502 means you cannot borrow a value as mutable because it is also borrowed as immutable."),
+        ("err_e0382", "Error E0382 is 'use of moved value' — the value was moved and then used again."),
+        ("ownership", "Ownership is Rust's approach to memory management without garbage collection."),
+        ("lifetimes", "Lifetimes describe how long references are valid relative to each other."),
+    ];
+
+    for (id, content) in docs {
+        rag_system
+            .add_documents(vec![Document {
+                id: id.to_string(),
+                content: content.to_string(),
+                metadata: std::collections::HashMap::new(),
+            }])
+            .await?;
+    }
+    println!("✓ 4 documents indexed in both the vector store and the BM25 index\n");
+
+    // --- Example 1: Exact-term query where keyword search shines ---
+    println!("Example 1: Exact Term (\"E0502\")");
+    println!("===============================\n");
+
+    for (label, alpha) in [("vector-only", 1.0), ("keyword-only", 0.0), ("hybrid", 0.5)] {
+        let results = rag_system.hybrid_search("E0502", 2, alpha).await?;
+        println!(
+            "{:<13} top: {} (score {:.3})",
+            label, results[0].document.id, results[0].score
+        );
+    }
+
+    // --- Example 2: Conceptual query where vectors shine ---
+    println!("\nExample 2: Conceptual Query");
+    println!("===========================\n");
+
+    let results = rag_system
+        .hybrid_search("how does Rust manage memory?", 2, 0.5)
+        .await?;
+    for result in &results {
+        println!("hybrid: {} (score {:.3})", result.document.id, result.score);
+    }
+
+    // Through the RAGTool the agent picks the mode per call:
+    //   {"operation": "search", "query": "E0502", "mode": "hybrid"}
+
+    Ok(())
+}
@@ -0,0 +1,79 @@
+//! # Example: LanceDB Vector Store Backend
+//!
+//! For embedded, persistent vector storage with decent scale, the
+//! `LanceDbVectorStore` (feature = "lancedb") implements the full
+//! `VectorStore` trait — add, search, delete, count, clear — storing
+//! metadata as JSON and supporting the metadata filters. No external
+//! service required: everything lives in a local directory.
+//!
+//! Run with:
+//!
+//! ```sh
+//! cargo run --example rag_lancedb --features lancedb
+//! ```
+
+use helios_engine::{Agent, Config, Document, LanceDbVectorStore, OpenAIEmbeddings, RAGSystem, RAGTool};
+
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - LanceDB Backend Example");
+    println!("==========================================\n");
+
+    let api_key = std::env::var("OPENAI_API_KEY").unwrap_or_default();
+
+    let embeddings = OpenAIEmbeddings::new(
+        "https://api.openai.com/v1/embeddings".to_string(),
+        api_key.clone(),
+    );
+
+    // --- Example 1: The store through RAGSystem ---
+    println!("Example 1: RAGSystem over LanceDB");
+    println!("=================================\n");
+
+    let store = LanceDbVectorStore::open("./lancedb_data", "documents", embeddings).await?;
+    let mut rag_system = RAGSystem::new(store);
+
+    let mut metadata = std::collections::HashMap::new();
+    metadata.insert("category".to_string(), "infra".to_string());
+
+    rag_system
+        .add_documents(vec![Document {
+            id: "runbook_1".to_string(),
+            content: "To restart the ingest service, run systemctl restart ingest.".to_string(),
+            metadata,
+        }])
+        .await?;
+
+    println!("count: {}", rag_system.count().await?);
+
+    let results = rag_system.search("how do I restart ingest?", 3).await?;
+    println!("top: {} ({:.3})\n", results[0].document.id, results[0].score);
+
+    // Everything persists in ./lancedb_data — reopen later and the data is
+    // still there, same as the Qdrant-backed store but embedded.
+
+    // --- Example 2: The RAGTool constructor ---
+    println!("Example 2: RAGTool::new_lancedb");
+    println!("===============================\n");
+
+    let config = Config::from_file("config.toml")?;
+
+    let rag_tool = RAGTool::new_lancedb(
+        "./lancedb_data",
+        "https://api.openai.com/v1/embeddings",
+        api_key,
+    )
+    .await?;
+
+    let mut agent = Agent::builder("RunbookAgent")
+        .config(config)
+        .system_prompt("You answer operational questions from the runbook store.")
+        .tool(Box::new(rag_tool))
+        .build()
+        .await?;
+
+    let response = agent.chat("How do I restart the ingest service?").await?;
+    println!("Agent: {}", response);
+
+    Ok(())
+}
@@ -0,0 +1,86 @@
+//! # Example: Vector Store Maintenance
+//!
+//! After months of adds and deletes, collections accumulate tombstones and
+//! orphaned chunks (children whose parent document was deleted). This
+//! example demonstrates the maintenance methods on the vector store trait,
+//! exposed through `RAGSystem`:
+//!
+//! - `integrity_check()` — reports orphaned chunks, dimension mismatches,
+//!   missing payload fields, and count drift vs. the manifest
+//! - `vacuum()` — removes orphans and compacts persistent files
+//! - `reindex()` — rebuilds ANN indexes
+//!
+//! All three are safe to run while reads continue, and report progress for
+//! large stores. The serve admin surface offers the same operations at
+//! `POST /admin/rag/maintenance`.
+
+use helios_engine::{Document, InMemoryVectorStore, OpenAIEmbeddings, RAGSystem};
+
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - RAG Maintenance Example");
+    println!("==========================================\n");
+
+    let embeddings = OpenAIEmbeddings::new(
+        "https://api.openai.com/v1/embeddings".to_string(),
+        std::env::var("OPENAI_API_KEY").unwrap_or_default(),
+    );
+
+    let vector_store = InMemoryVectorStore::new(embeddings);
+    let mut rag_system = RAGSystem::new(vector_store);
+
+    // Build up and churn a collection a bit.
+    for i in 0..20 {
+        rag_system
+            .add_documents(vec![Document {
+                id: format!("doc_{}", i),
+                content: format!("Document number {} about various topics.", i),
+                metadata: std::collections::HashMap::new(),
+            }])
+            .await?;
+    }
+    for i in 0..8 {
+        rag_system.delete_document(&format!("doc_{}", i)).await?;
+    }
+    println!("✓ Collection populated and churned (20 adds, 8 deletes)\n");
+
+    // --- Example 1: Integrity check ---
+    println!("Example 1: integrity_check");
+    println!("==========================\n");
+
+    let report = rag_system.integrity_check().await?;
+    println!("orphaned chunks:        {}", report.orphaned_chunks);
+    println!("dimension mismatches:   {}", report.dimension_mismatches);
+    println!("missing payload fields: {}", report.missing_payload_fields);
+    println!("count drift:            {}\n", report.count_drift);
+
+    // --- Example 2: Vacuum ---
+    println!("Example 2: vacuum");
+    println!("=================\n");
+
+    let outcome = rag_system
+        .vacuum()
+        .on_progress(|p| println!("  vacuum progress: {}%", p.percent))
+        .await?;
+    println!("removed {} orphans, reclaimed {} bytes\n", outcome.removed, outcome.bytes_reclaimed);
+
+    // --- Example 3: Reindex ---
+    println!("Example 3: reindex");
+    println!("==================\n");
+
+    rag_system.reindex().await?;
+    println!("✓ ANN index rebuilt; reads were served throughout\n");
+
+    // A follow-up check should come back clean.
+    let report = rag_system.integrity_check().await?;
+    println!(
+        "post-maintenance orphans: {} (expected 0)",
+        report.orphaned_chunks
+    );
+
+    // Over HTTP:
+    //   curl -X POST http://127.0.0.1:8000/admin/rag/maintenance \
+    //     -d '{"operation": "vacuum"}'
+
+    Ok(())
+}
@@ -0,0 +1,84 @@
+//! # Example: Persistent On-Disk Vector Store
+//!
+//! Persistence without running Qdrant: the `FileVectorStore` implements the
+//! same `VectorStore` trait as `InMemoryVectorStore`, saving documents,
+//! embeddings, and metadata to a single directory. The store loads lazily on
+//! first use, appends incrementally on `add`, and compacts on
+//! `delete`/`clear`. Corrupted files produce a clear error rather than a
+//! panic.
+//!
+//! ## Prerequisites
+//!
+//! ```sh
+//! export OPENAI_API_KEY=your-key
+//! ```
+
+use helios_engine::{Agent, Config, Document, FileVectorStore, OpenAIEmbeddings, RAGSystem, RAGTool};
+
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - Persistent Vector Store Example");
+    println!("==================================================\n");
+
+    let api_key = std::env::var("OPENAI_API_KEY").unwrap_or_default();
+
+    let embeddings = OpenAIEmbeddings::new(
+        "https://api.openai.com/v1/embeddings".to_string(),
+        api_key.clone(),
+    );
+
+    // --- Example 1: Store documents, then reopen the same path ---
+    println!("Example 1: Restart Survival");
+    println!("===========================\n");
+
+    {
+        let store = FileVectorStore::open("./vector_data", embeddings.clone())?;
+        let mut rag_system = RAGSystem::new(store);
+
+        rag_system
+            .add_documents(vec![Document {
+                id: "persist_1".to_string(),
+                content: "The quarterly report is due on the first Monday of October.".to_string(),
+                metadata: std::collections::HashMap::new(),
+            }])
+            .await?;
+        println!("✓ Document written to ./vector_data");
+    } // store dropped — simulates the process exiting
+
+    // A "new process": open the same path and search immediately.
+    let store = FileVectorStore::open("./vector_data", embeddings)?;
+    let mut rag_system = RAGSystem::new(store);
+
+    let results = rag_system.search("when is the quarterly report due?", 1).await?;
+    println!("✓ Reloaded: {} (score {:.3})\n", results[0].document.content, results[0].score);
+
+    // Deletes trigger compaction so the files don't grow forever.
+    rag_system.delete_document("persist_1").await?;
+    println!("✓ Delete compacted the on-disk segments\n");
+
+    // --- Example 2: The RAGTool convenience constructor ---
+    println!("Example 2: RAGTool::new_persistent");
+    println!("==================================\n");
+
+    let config = Config::from_file("config.toml")?;
+
+    let rag_tool = RAGTool::new_persistent(
+        "./vector_data",
+        "https://api.openai.com/v1/embeddings",
+        std::env::var("OPENAI_API_KEY").unwrap_or_default(),
+    )?;
+
+    let mut agent = Agent::builder("PersistentRagAgent")
+        .config(config)
+        .system_prompt("Your knowledge base persists across restarts.")
+        .tool(Box::new(rag_tool))
+        .build()
+        .await?;
+
+    let response = agent
+        .chat("Store this: 'Deploys happen every Thursday.' It should survive a restart.")
+        .await?;
+    println!("Agent: {}", response);
+
+    Ok(())
+}
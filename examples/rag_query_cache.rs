@@ -0,0 +1,86 @@
+//! # Example: Stale-While-Revalidate RAG Query Cache
+//!
+//! Agents often search the same query several times within one conversation
+//! as they iterate, each time paying embedding plus vector-store latency.
+//! This example demonstrates the per-session RAG query cache: results are
+//! keyed by normalized query + filter + options, returned immediately when
+//! cached, refreshed in the background once they pass the TTL, and
+//! invalidated automatically when documents are added or deleted during the
+//! session. The RAGTool's result carries a `cached: bool` marker.
+//!
+//! ## Prerequisites
+//!
+//! ```sh
+//! export OPENAI_API_KEY=your-key
+//! ```
+
+use std::time::Duration;
+
+use helios_engine::rag::QueryCacheOptions;
+use helios_engine::{Agent, Config, RAGTool};
+
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - RAG Query Cache Example");
+    println!("==========================================\n");
+
+    let embedding_api_key = std::env::var("OPENAI_API_KEY").unwrap_or_default();
+
+    // Cache per session: serve cached results instantly, refresh in the
+    // background after 60 seconds, never share across sessions.
+    let rag_tool = RAGTool::new_in_memory("https://api.openai.com/v1/embeddings", embedding_api_key)
+        .with_query_cache(
+            QueryCacheOptions::default()
+                .ttl(Duration::from_secs(60))
+                .stale_while_revalidate(true),
+        );
+
+    let config = Config::from_file("config.toml")?;
+
+    let mut agent = Agent::builder("CachedRagAgent")
+        .config(config)
+        .system_prompt("You answer questions from your knowledge base using the rag tool.")
+        .tool(Box::new(rag_tool))
+        .max_iterations(10)
+        .build()
+        .await?;
+
+    // Seed some knowledge.
+    agent
+        .chat("Store this: 'Helios Engine is a Rust framework for building LLM agents.'")
+        .await?;
+
+    // --- Example 1: Repeated queries hit the cache ---
+    println!("Example 1: Cache Hits");
+    println!("=====================\n");
+
+    let start = std::time::Instant::now();
+    agent.chat("What is Helios Engine?").await?;
+    println!("first query (cold):  {:?}", start.elapsed());
+
+    let start = std::time::Instant::now();
+    agent.chat("Tell me again — what is Helios Engine?").await?;
+    println!("second query (warm): {:?}\n", start.elapsed());
+
+    // --- Example 2: Adding a document invalidates affected entries ---
+    println!("Example 2: Invalidation on Write");
+    println!("================================\n");
+
+    agent
+        .chat("Also store: 'Helios Engine supports local GGUF models.'")
+        .await?;
+    let response = agent.chat("What do you know about Helios Engine now?").await?;
+    println!("Agent: {}\n", response);
+
+    // --- Example 3: Counters ---
+    println!("Example 3: Hit/Miss Counters");
+    println!("============================\n");
+
+    let stats = agent.rag_cache_stats();
+    println!("hits:          {}", stats.hits);
+    println!("misses:        {}", stats.misses);
+    println!("invalidations: {}", stats.invalidations);
+    println!("bg refreshes:  {}", stats.background_refreshes);
+
+    Ok(())
+}
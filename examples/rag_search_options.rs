@@ -0,0 +1,95 @@
+//! # Example: Search Thresholds and MMR Diversification
+//!
+//! `RAGSystem::search` returns the top-k even when everything is irrelevant,
+//! and often returns near-duplicate chunks. This example demonstrates
+//! `search_with_options`: a `min_score` cosine-similarity threshold that
+//! drops weak results, and an optional MMR (maximal marginal relevance)
+//! re-ranking pass whose `lambda` trades relevance against diversity,
+//! computed from the stored embeddings. The same options are available as
+//! `RAGTool` arguments.
+//!
+//! ## Prerequisites
+//!
+//! ```sh
+//! export OPENAI_API_KEY=your-key
+//! ```
+
+use helios_engine::rag::SearchOptions;
+use helios_engine::{Document, InMemoryVectorStore, OpenAIEmbeddings, RAGSystem};
+
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - Search Options Example");
+    println!("=========================================\n");
+
+    let embeddings = OpenAIEmbeddings::new(
+        "https://api.openai.com/v1/embeddings".to_string(),
+        std::env::var("OPENAI_API_KEY").unwrap_or_default(),
+    );
+
+    let vector_store = InMemoryVectorStore::new(embeddings);
+    let mut rag_system = RAGSystem::new(vector_store);
+
+    // Several near-duplicate docs about one topic, plus unrelated ones.
+    let docs = [
+        ("tokio_1", "Tokio is an async runtime for Rust."),
+        ("tokio_2", "Tokio is Rust's most popular asynchronous runtime."),
+        ("tokio_3", "The Tokio runtime powers async Rust applications."),
+        ("serde", "Serde is a serialization framework for Rust."),
+        ("cooking", "Caramelizing onions takes about forty minutes."),
+    ];
+
+    for (id, content) in docs {
+        rag_system
+            .add_documents(vec![Document {
+                id: id.to_string(),
+                content: content.to_string(),
+                metadata: std::collections::HashMap::new(),
+            }])
+            .await?;
+    }
+
+    // --- Example 1: min_score filters out the irrelevant ---
+    println!("Example 1: min_score");
+    println!("====================\n");
+
+    let options = SearchOptions {
+        limit: 5,
+        min_score: Some(0.75),
+        mmr_lambda: None,
+        filter: None,
+    };
+
+    let results = rag_system
+        .search_with_options("rust async runtime", options)
+        .await?;
+    println!("{} results above 0.75 (the cooking doc is gone):", results.len());
+    for result in &results {
+        println!("  {} ({:.3})", result.document.id, result.score);
+    }
+
+    // --- Example 2: MMR keeps the set diverse ---
+    println!("\nExample 2: MMR Diversification");
+    println!("==============================\n");
+
+    let options = SearchOptions {
+        limit: 3,
+        min_score: None,
+        mmr_lambda: Some(0.5), // 1.0 = pure relevance, 0.0 = pure diversity
+        filter: None,
+    };
+
+    let results = rag_system
+        .search_with_options("rust async runtime", options)
+        .await?;
+    println!("top 3 with MMR (only one tokio duplicate survives):");
+    for result in &results {
+        println!("  {} ({:.3})", result.document.id, result.score);
+    }
+
+    // Through the RAGTool:
+    //   {"operation": "search", "query": "...", "min_score": 0.75,
+    //    "mmr_lambda": 0.5}
+
+    Ok(())
+}
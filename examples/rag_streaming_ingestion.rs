@@ -0,0 +1,70 @@
+//! # Example: Bounded-Memory Streaming Ingestion
+//!
+//! Ingesting a 2 GB JSONL corpus shouldn't require loading it into memory.
+//! This example demonstrates streaming ingestion: loaders expose
+//! `stream_documents(path)` reading incrementally (line-by-line for
+//! JSONL/CSV, chunked readers for text), and `RAGSystem::ingest_stream`
+//! pipelines chunking, batched embedding, and store upserts with bounded
+//! channel buffers — peak memory stays flat regardless of input size.
+//! Progress (docs/sec, bytes processed) arrives via a callback, and a
+//! failure mid-stream leaves a resumable checkpoint in the manifest.
+//!
+//! ## Prerequisites
+//!
+//! ```sh
+//! export OPENAI_API_KEY=your-key
+//! ```
+
+use helios_engine::rag::{loaders, IngestStreamOptions};
+use helios_engine::{InMemoryVectorStore, OpenAIEmbeddings, RAGSystem};
+
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - Streaming Ingestion Example");
+    println!("==============================================\n");
+
+    let embeddings = OpenAIEmbeddings::new(
+        "https://api.openai.com/v1/embeddings".to_string(),
+        std::env::var("OPENAI_API_KEY").unwrap_or_default(),
+    );
+
+    let vector_store = InMemoryVectorStore::new(embeddings);
+    let mut rag_system = RAGSystem::new(vector_store);
+
+    // --- Example 1: Stream a large JSONL corpus ---
+    println!("Example 1: ingest_stream");
+    println!("========================\n");
+
+    let stream = loaders::stream_documents("corpus.jsonl")?;
+
+    let options = IngestStreamOptions::default()
+        .embed_batch_size(64)
+        .buffer_documents(256) // bounded channels cap peak memory
+        .on_progress(|p| {
+            println!(
+                "  {:>8} docs  {:>6.1} docs/s  {:>10} bytes",
+                p.documents, p.docs_per_sec, p.bytes_processed
+            );
+        });
+
+    let summary = rag_system.ingest_stream(stream, options).await?;
+    println!("\n✓ ingested {} documents ({} chunks)\n", summary.documents, summary.chunks_added);
+
+    // --- Example 2: Resume after an abort ---
+    println!("Example 2: Resumable Checkpoints");
+    println!("================================\n");
+
+    // If the previous run died mid-stream, the manifest holds the byte
+    // offset / record index it reached; resuming skips everything already
+    // committed to the store.
+    let stream = loaders::stream_documents("corpus.jsonl")?;
+    let summary = rag_system
+        .ingest_stream(stream, IngestStreamOptions::default().resume_from_checkpoint())
+        .await?;
+    println!(
+        "resumed run: {} new documents ({} skipped from checkpoint)",
+        summary.documents, summary.skipped
+    );
+
+    Ok(())
+}
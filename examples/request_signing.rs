@@ -0,0 +1,83 @@
+//! # Example: Outbound Request Signing
+//!
+//! This example demonstrates the outbound signing facility for webhooks and
+//! `HttpRequestTool` calls to internal services. A configured `RequestSigner`
+//! adds `X-Helios-Signature`, `X-Helios-Timestamp`, and `X-Helios-Key-Id`
+//! headers computed as HMAC-SHA256 over a canonicalized body, and receiving
+//! services verify them with `helios_engine::signing::verify`.
+//!
+//! Signing keys are loaded from the environment (or config/keyring) and are
+//! never printed or included in debug output.
+//!
+//! ## Prerequisites
+//!
+//! ```sh
+//! export HELIOS_SIGNING_KEY=your-shared-secret
+//! ```
+
+use helios_engine::signing::{self, RequestSigner, SigningKey};
+use helios_engine::{Agent, Config, HttpRequestTool, WebhookNotifier};
+
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - Request Signing Example");
+    println!("==========================================\n");
+
+    // Load the signing key from the environment. The key material is kept
+    // opaque: `SigningKey` deliberately redacts itself in Debug output.
+    let key = SigningKey::from_env("HELIOS_SIGNING_KEY")?;
+    let signer = RequestSigner::hmac_sha256("primary-2026", key);
+    println!("✓ Signer created with key id: {}", signer.key_id());
+
+    // --- Example 1: Sign outbound HttpRequestTool calls ---
+    println!("\nExample 1: Signed HttpRequestTool");
+    println!("=================================\n");
+
+    let config = Config::from_file("config.toml")?;
+    let http_tool = HttpRequestTool::new().with_signer(signer.clone());
+
+    let mut agent = Agent::builder("InternalServicesAgent")
+        .config(config.clone())
+        .system_prompt("You call internal HTTP services to answer questions.")
+        .tool(Box::new(http_tool))
+        .build()
+        .await?;
+
+    let response = agent
+        .chat("Fetch the current status from https://internal.example.com/status")
+        .await?;
+    println!("Agent: {}\n", response);
+
+    // --- Example 2: Sign webhook notifications ---
+    println!("Example 2: Signed WebhookNotifier");
+    println!("=================================\n");
+
+    let notifier = WebhookNotifier::new("https://internal.example.com/hooks/agent-events")
+        .with_signer(signer.clone());
+    notifier.notify("agent.run.completed", &response).await?;
+    println!("✓ Webhook delivered with signature headers\n");
+
+    // --- Example 3: Verify a signature on the receiving side ---
+    println!("Example 3: Verification");
+    println!("=======================\n");
+
+    // A receiving Rust service reconstructs the canonical body and verifies
+    // the headers. Verification tolerates a bounded amount of clock skew and
+    // rejects requests outside the replay window (default: 5 minutes).
+    let body = br#"{"event": "agent.run.completed"}"#;
+    let headers = signer.sign(body)?;
+
+    match signing::verify(body, &headers, signer.verification_key()) {
+        Ok(()) => println!("✓ Signature verified"),
+        Err(e) => println!("✗ Rejected: {}", e),
+    }
+
+    // Tampered bodies are rejected even when the headers are untouched.
+    let tampered = br#"{"event": "agent.run.failed"}"#;
+    match signing::verify(tampered, &headers, signer.verification_key()) {
+        Ok(()) => println!("✗ Tampered body was accepted (this should not happen)"),
+        Err(e) => println!("✓ Tampered body rejected: {}", e),
+    }
+
+    Ok(())
+}
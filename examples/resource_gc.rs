@@ -0,0 +1,102 @@
+//! # Example: Scoped Resource Cleanup for Tools
+//!
+//! Tools create temp files, artifacts, attachment indexes, and speculative
+//! results that accumulate over long-lived processes. This example
+//! demonstrates the scoped resource tracker in the `ToolContext`: tools
+//! register cleanups (paths, artifact ids, closures) tagged with a scope —
+//! `Turn`, `Session`, `Run`, or `Process` — and the agent invokes the right
+//! cleanups when each scope ends. Failures are logged but not fatal, and a
+//! periodic sweep reclaims leaked session-scoped resources after a TTL.
+
+use helios_engine::tools::{ResourceScope, ToolContext};
+use helios_engine::{Agent, Config, FileWriteTool, Tool, ToolParameter, ToolResult};
+use async_trait::async_trait;
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// A tool that produces a temp artifact and registers it for cleanup.
+struct RenderChartTool;
+
+#[async_trait]
+impl Tool for RenderChartTool {
+    fn name(&self) -> &str {
+        "render_chart"
+    }
+
+    fn description(&self) -> &str {
+        "Render a chart image from inline data"
+    }
+
+    fn parameters(&self) -> HashMap<String, ToolParameter> {
+        let mut params = HashMap::new();
+        params.insert(
+            "data".to_string(),
+            ToolParameter {
+                param_type: "string".to_string(),
+                description: "CSV data to plot".to_string(),
+                required: Some(true),
+            },
+        );
+        params
+    }
+
+    async fn execute_with_context(
+        &self,
+        args: Value,
+        ctx: &ToolContext,
+    ) -> helios_engine::Result<ToolResult> {
+        let path = std::env::temp_dir().join(format!("chart_{}.png", uuid::Uuid::new_v4()));
+        std::fs::write(&path, args["data"].as_str().unwrap_or_default())?;
+
+        // Gone as soon as this turn's chat() returns.
+        ctx.register_cleanup(ResourceScope::Turn, path.clone());
+
+        Ok(ToolResult::success(format!("chart written to {}", path.display())))
+    }
+}
+
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - Resource GC Example");
+    println!("======================================\n");
+
+    let config = Config::from_file("config.toml")?;
+
+    let mut agent = Agent::builder("ArtifactAgent")
+        .config(config)
+        .system_prompt("You render charts and write files as asked.")
+        .tool(Box::new(RenderChartTool))
+        // Built-in tools (temp write modes, attachment indexes) already
+        // register their ephemera with the tracker.
+        .tool(Box::new(FileWriteTool::rooted(std::env::temp_dir())))
+        .build()
+        .await?;
+
+    // --- Example 1: Turn-scoped resources vanish after chat() ---
+    println!("Example 1: Turn Scope");
+    println!("=====================\n");
+
+    let response = agent.chat("Render a chart of: a,1\\nb,2\\nc,3").await?;
+    println!("Agent: {}", response);
+    println!("turn ended → chart temp file already reclaimed\n");
+
+    // --- Example 2: Session scope and forced GC ---
+    println!("Example 2: Session Scope + force_gc");
+    println!("===================================\n");
+
+    // Session-scoped resources persist across turns until the session ends
+    // (or the TTL sweep catches leaks). Hosts can also force it:
+    let reclaimed = agent.force_gc(ResourceScope::Session).await?;
+    println!("force_gc(Session) reclaimed {} resources ({} bytes)\n", reclaimed.count, reclaimed.bytes);
+
+    // --- Example 3: Metrics ---
+    println!("Example 3: Metrics");
+    println!("==================\n");
+
+    let metrics = agent.resource_gc_metrics();
+    println!("reclaimed (turn):    {}", metrics.turn_reclaimed);
+    println!("reclaimed (session): {}", metrics.session_reclaimed);
+    println!("cleanup failures:    {} (logged, never fatal)", metrics.failures);
+
+    Ok(())
+}
@@ -0,0 +1,74 @@
+//! # Example: Runtime Tool Registration
+//!
+//! Tools no longer have to be attached through `AgentBuilder` before
+//! `build()`. This example demonstrates `agent.add_tool(...)`,
+//! `agent.remove_tool(name)`, and `agent.replace_tool(name, tool)`, which
+//! take effect on the next `chat()` call. Typical uses: enabling a RAG tool
+//! only after the user uploads documents, or hot-swapping an HTTP tool with
+//! fresh credentials.
+//!
+//! Removal mid-conversation is safe: pending tool-result messages already in
+//! the `ChatSession` are preserved.
+
+use helios_engine::{Agent, CalculatorTool, Config, EchoTool, HttpRequestTool, RAGTool};
+
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - Runtime Tools Example");
+    println!("========================================\n");
+
+    let config = Config::from_file("config.toml")?;
+
+    // Build the agent with only a calculator to start.
+    let mut agent = Agent::builder("DynamicAgent")
+        .config(config)
+        .system_prompt("You are a helpful assistant. Use tools when available.")
+        .tool(Box::new(CalculatorTool))
+        .build()
+        .await?;
+
+    let response = agent.chat("What is 6 * 7?").await?;
+    println!("Agent: {}\n", response);
+
+    // --- Example 1: Add a tool after build ---
+    println!("Example 1: add_tool");
+    println!("===================\n");
+
+    // Pretend the user just uploaded documents — only now do we enable RAG.
+    let embedding_api_key = std::env::var("OPENAI_API_KEY").unwrap_or_default();
+    let rag_tool = RAGTool::new_in_memory("https://api.openai.com/v1/embeddings", embedding_api_key);
+    agent.add_tool(Box::new(rag_tool));
+    println!("✓ RAG tool enabled for subsequent turns\n");
+
+    let response = agent
+        .chat("Store this note: the deployment window is Tuesdays at 10:00 UTC.")
+        .await?;
+    println!("Agent: {}\n", response);
+
+    // --- Example 2: Remove a tool ---
+    println!("Example 2: remove_tool");
+    println!("======================\n");
+
+    let removed = agent.remove_tool("calculator");
+    println!("calculator removed: {}", removed);
+    let removed_again = agent.remove_tool("calculator");
+    println!("second removal returns: {}\n", removed_again);
+
+    // --- Example 3: Replace a tool in place ---
+    println!("Example 3: replace_tool");
+    println!("=======================\n");
+
+    // Hot-swap the HTTP tool with one carrying different credentials; the
+    // name stays stable so the conversation history remains coherent.
+    agent.add_tool(Box::new(HttpRequestTool::new()));
+    let refreshed = HttpRequestTool::new().with_bearer_token(std::env::var("API_TOKEN").unwrap_or_default());
+    agent.replace_tool("http_request", Box::new(refreshed));
+    println!("✓ http_request swapped with refreshed credentials\n");
+
+    // The conversation continues seamlessly with the updated tool set.
+    agent.add_tool(Box::new(EchoTool));
+    let response = agent.chat("Echo the word 'reconfigured'.").await?;
+    println!("Agent: {}", response);
+
+    Ok(())
+}
@@ -0,0 +1,72 @@
+//! # Example: Semantic Diff on Regenerated Answers
+//!
+//! When a user hits "regenerate" (or self-consistency picks a different
+//! candidate), a UI wants to show what actually changed. This example
+//! demonstrates `helios_engine::diff::semantic_diff`, a text diff tuned for
+//! prose: sentence- and word-level hunks rather than lines, move detection
+//! for reordered bullet lists, and elision of long unchanged spans. It is
+//! wired into `regenerate_last()`, so the run outcome carries the diff
+//! against the previously returned answer.
+
+use helios_engine::diff::{self, ChangeType};
+use helios_engine::{Agent, Config};
+
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - Semantic Diff Example");
+    println!("========================================\n");
+
+    // --- Example 1: The diff utility on its own ---
+    println!("Example 1: diff::semantic_diff");
+    println!("==============================\n");
+
+    let old = "Rust guarantees memory safety. It uses a borrow checker. \
+               Popular crates include serde and tokio.";
+    let new = "Rust guarantees memory safety without garbage collection. \
+               Popular crates include tokio and serde. It uses a borrow checker.";
+
+    let hunks = diff::semantic_diff(old, new);
+    for hunk in &hunks {
+        match hunk.change_type {
+            ChangeType::Unchanged => println!("  = {}", hunk.elided_preview()),
+            ChangeType::Inserted => println!("  + {}", hunk.text),
+            ChangeType::Deleted => println!("  - {}", hunk.text),
+            ChangeType::Moved => println!("  ↕ {} (moved)", hunk.text),
+            ChangeType::Replaced => {
+                println!("  ~ {} → {}", hunk.old_text.as_deref().unwrap_or(""), hunk.text)
+            }
+        }
+    }
+
+    // --- Example 2: Regeneration with a diff on the outcome ---
+    println!("\nExample 2: regenerate_last");
+    println!("==========================\n");
+
+    let config = Config::from_file("config.toml")?;
+
+    let mut agent = Agent::builder("Writer")
+        .config(config)
+        .system_prompt("You write concise technical explanations.")
+        .build()
+        .await?;
+
+    let first = agent.chat("Explain what a mutex is in two sentences.").await?;
+    println!("First answer:\n{}\n", first);
+
+    let second = agent.regenerate_last().await?;
+    println!("Regenerated answer:\n{}\n", second);
+
+    println!("What changed:");
+    for hunk in agent.last_run_outcome().regeneration_diff() {
+        if hunk.change_type != ChangeType::Unchanged {
+            println!("  {:?}: {}", hunk.change_type, hunk.text);
+        }
+    }
+
+    // Over HTTP, request the diff with a flag and read it from the debug
+    // field of the response:
+    //   {"model": "...", "messages": [...], "regenerate": true,
+    //    "include_diff": true}  →  "helios_diff": [...]
+
+    Ok(())
+}
@@ -0,0 +1,55 @@
+//! # Example: API Key Auth and Rate Limiting
+//!
+//! Exposing the server beyond localhost needs an auth layer. This example
+//! demonstrates `ServerConfig`: accepted bearer tokens (from `config.toml`
+//! or environment variables — never logged), a 401 with an OpenAI-style
+//! error body on failure, and per-key token-bucket rate limiting that
+//! returns 429 with `Retry-After`. Auth applies to the v1 endpoints and
+//! custom endpoints alike unless an endpoint opts out.
+//!
+//! ```bash
+//! # Accepted:
+//! curl http://localhost:8080/v1/chat/completions \
+//!   -H "Authorization: Bearer $HELIOS_API_KEY" \
+//!   -d '{"model": "helios", "messages": [{"role": "user", "content": "hi"}]}'
+//!
+//! # Rejected with a 401 error body:
+//! curl http://localhost:8080/v1/chat/completions -d '{}'
+//! ```
+
+use helios_engine::serve::{self, ServerConfig};
+use helios_engine::{Agent, Config};
+
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - Server Auth Example");
+    println!("======================================\n");
+
+    let config = Config::from_file("config.toml")?;
+
+    let agent = Agent::builder("helios")
+        .config(config)
+        .system_prompt("You are a helpful assistant.")
+        .build()
+        .await?;
+
+    let server_config = ServerConfig::new()
+        // Keys from the environment; [server.api_keys] in config.toml also
+        // works. Keys are compared in constant time and never logged.
+        .api_key_from_env("HELIOS_API_KEY")
+        .api_key_from_env("HELIOS_API_KEY_CI")
+        // Or plug in your own validator (e.g. look up a database):
+        // .api_key_validator(|key| async move { key.starts_with("hk-") })
+        //
+        // 60 requests/minute per key, token-bucket, 429 + Retry-After when
+        // exhausted.
+        .rate_limit_per_key(60);
+
+    println!("Serving with bearer auth on http://localhost:8080");
+    println!("Set HELIOS_API_KEY before starting; requests without it get 401.\n");
+
+    serve::start_server_with_agent_and_config(agent, "helios".to_string(), "127.0.0.1:8080", server_config)
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,55 @@
+//! # Example: Client-Supplied Tools
+//!
+//! OpenAI clients often send their own `tools` array and run the calls
+//! themselves. This example enables pass-through mode: when a request
+//! includes `tools`, the server forwards them to the underlying LLM client
+//! and returns the model's tool calls with `finish_reason: "tool_calls"`
+//! instead of executing anything server-side. Follow-up requests carrying
+//! `role: "tool"` messages thread straight through. Config chooses whether
+//! client tools merge with or replace the agent's registered tools.
+//!
+//! ```bash
+//! curl http://localhost:8080/v1/chat/completions -d '{
+//!   "model": "helios",
+//!   "messages": [{"role": "user", "content": "Weather in Paris?"}],
+//!   "tools": [{"type": "function", "function": {"name": "get_weather",
+//!     "parameters": {"type": "object", "properties": {"city": {"type": "string"}}}}}]
+//! }'
+//! # → finish_reason "tool_calls"; execute get_weather yourself, then send
+//! #   the result back as a role:"tool" message.
+//! ```
+
+use helios_engine::serve::{self, ClientToolMode, ServeOptions};
+use helios_engine::tools::CalculatorTool;
+use helios_engine::{Agent, Config};
+
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - Client Tools Example");
+    println!("=======================================\n");
+
+    let config = Config::from_file("config.toml")?;
+
+    let agent = Agent::builder("helios")
+        .config(config)
+        .system_prompt("You are a helpful assistant.")
+        // Server-side tools still exist; the mode below decides how they
+        // interact with tools the client sends.
+        .tool(Box::new(CalculatorTool))
+        .build()
+        .await?;
+
+    let options = ServeOptions::default()
+        // Merge: the model sees both the agent's tools and the client's.
+        // Use ClientToolMode::Replace to hide server tools when the client
+        // supplies its own.
+        .client_tools(ClientToolMode::Merge);
+
+    println!("Serving with client-tool pass-through on http://localhost:8080");
+    println!("Requests with a 'tools' array get tool_calls back, unexecuted.\n");
+
+    serve::start_server_with_agent_and_options(agent, "helios".to_string(), "127.0.0.1:8080", options)
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,68 @@
+//! # Example: Config-Defined Custom Endpoints
+//!
+//! Custom endpoints can now live in `config.toml` instead of code. A
+//! `[[serve.custom_endpoints]]` array declares method, path, a static
+//! response or template, status code, an optional per-endpoint auth
+//! override, and an optional per-endpoint rate limit. The config is
+//! validated at load time — bad path syntax, unknown methods, and duplicate
+//! method+path pairs are rejected with errors naming the offending entry.
+//!
+//! ## Example config.toml
+//!
+//! ```toml
+//! [[serve.custom_endpoints]]
+//! method = "GET"
+//! path = "/status"
+//! response = '{"status": "ok"}'
+//! status = 200
+//!
+//! [[serve.custom_endpoints]]
+//! method = "GET"
+//! path = "/version"
+//! response = '{"version": "{{crate_version}}"}'
+//! require_auth = false
+//! rate_limit_per_minute = 60
+//! ```
+
+use helios_engine::{serve, Agent, CalculatorTool, Config};
+
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - Config-Defined Endpoints Example");
+    println!("===================================================\n");
+
+    // Validation happens here: a malformed endpoint entry fails the load
+    // with a message like "serve.custom_endpoints[2]: duplicate GET /status".
+    let config = Config::from_file("config.toml")?;
+
+    if let Some(endpoints) = &config.serve.custom_endpoints {
+        println!("✓ Config declares {} custom endpoints:", endpoints.len());
+        for ep in endpoints {
+            println!("  {} {}", ep.method, ep.path);
+        }
+    }
+
+    let agent = Agent::builder("API Agent")
+        .config(config.clone())
+        .system_prompt("You are a helpful assistant.")
+        .tool(Box::new(CalculatorTool))
+        .build()
+        .await?;
+
+    // The server builds its router from the config when present. Endpoints
+    // added programmatically are merged in; on a method+path conflict the
+    // programmatic one wins and a warning is logged.
+    println!("\nStarting server on http://127.0.0.1:8000");
+    println!("Try: curl http://127.0.0.1:8000/status");
+    println!("     curl http://127.0.0.1:8000/version");
+
+    serve::ServerBuilder::new(agent, "local-model")
+        .from_config(&config)
+        .custom_endpoint("GET", "/build-info", || async {
+            serde_json::json!({ "built_at": env!("CARGO_PKG_VERSION") })
+        })
+        .serve("127.0.0.1:8000")
+        .await?;
+
+    Ok(())
+}
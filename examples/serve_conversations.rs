@@ -0,0 +1,55 @@
+//! # Example: Stateful Server Conversations
+//!
+//! By default every request replays the full message list and unrelated
+//! clients pollute the agent's internal session. This example enables the
+//! opt-in session mode: when a request carries a `user` field or an
+//! `X-Conversation-Id` header, the server keeps a per-conversation
+//! `ChatSession` in a TTL-bounded map, appends only the new user message,
+//! and runs the agent against that session. `GET /v1/conversations/:id`
+//! inspects history, `DELETE` clears it, and idle conversations are
+//! evicted to bound memory.
+//!
+//! ```bash
+//! # Two turns in the same conversation — no history replay needed:
+//! curl http://localhost:8080/v1/chat/completions \
+//!   -H "X-Conversation-Id: alice-1" \
+//!   -d '{"model": "helios", "messages": [{"role": "user", "content": "My name is Alice."}]}'
+//! curl http://localhost:8080/v1/chat/completions \
+//!   -H "X-Conversation-Id: alice-1" \
+//!   -d '{"model": "helios", "messages": [{"role": "user", "content": "What is my name?"}]}'
+//!
+//! curl http://localhost:8080/v1/conversations/alice-1
+//! curl -X DELETE http://localhost:8080/v1/conversations/alice-1
+//! ```
+
+use std::time::Duration;
+
+use helios_engine::serve::{self, ServeOptions};
+use helios_engine::{Agent, Config};
+
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - Stateful Conversations Example");
+    println!("=================================================\n");
+
+    let config = Config::from_file("config.toml")?;
+
+    let agent = Agent::builder("helios")
+        .config(config)
+        .system_prompt("You are a helpful assistant with a good memory.")
+        .build()
+        .await?;
+
+    let options = ServeOptions::default()
+        .sessions(true)
+        // Conversations idle for half an hour are evicted.
+        .session_ttl(Duration::from_secs(30 * 60));
+
+    println!("Serving with sessions on http://localhost:8080");
+    println!("Send an X-Conversation-Id header (or a 'user' field) to keep state.\n");
+
+    serve::start_server_with_agent_and_options(agent, "helios".to_string(), "127.0.0.1:8080", options)
+        .await?;
+
+    Ok(())
+}
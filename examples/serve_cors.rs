@@ -0,0 +1,55 @@
+//! # Example: CORS and Request-Size Limits
+//!
+//! Browsers can't call the served API without CORS headers, and an
+//! unbounded request body is an easy way to fall over. This example
+//! configures both through `ServerConfig`: allowed origins (an exact list
+//! or `*`), allowed headers and methods, preflight max-age, and a
+//! `max_body_bytes` cap that returns 413 with an OpenAI-style error body.
+//! Preflight OPTIONS requests are answered for the v1 endpoints and custom
+//! endpoints alike. The same settings can live in `config.toml` under a
+//! `[server]` table:
+//!
+//! ```toml
+//! [server]
+//! allowed_origins = ["https://app.example.com"]
+//! max_body_bytes = 1048576
+//! ```
+
+use helios_engine::serve::{self, CorsConfig, ServerConfig};
+use helios_engine::{Agent, Config};
+
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - CORS Example");
+    println!("===============================\n");
+
+    let config = Config::from_file("config.toml")?;
+
+    let agent = Agent::builder("helios")
+        .config(config)
+        .system_prompt("You are a helpful assistant.")
+        .build()
+        .await?;
+
+    let server_config = ServerConfig::new()
+        .cors(
+            CorsConfig::new()
+                // Use .any_origin() for "*" during development.
+                .allow_origin("https://app.example.com")
+                .allow_origin("http://localhost:5173")
+                .allow_methods(&["GET", "POST", "DELETE"])
+                .allow_headers(&["Authorization", "Content-Type", "X-Conversation-Id"])
+                // Browsers cache the preflight answer for ten minutes.
+                .max_age_secs(600),
+        )
+        // Bodies past 1 MiB get a 413 with an OpenAI-style error.
+        .max_body_bytes(1024 * 1024);
+
+    println!("Serving with CORS on http://localhost:8080");
+    println!("Browser clients from the allowed origins can call the API directly.\n");
+
+    serve::start_server_with_agent_and_config(agent, "helios".to_string(), "127.0.0.1:8080", server_config)
+        .await?;
+
+    Ok(())
+}
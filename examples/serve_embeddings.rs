@@ -0,0 +1,53 @@
+//! # Example: /v1/embeddings Endpoint
+//!
+//! The crate already ships embeddings providers for RAG — the server can
+//! expose the same provider at `/v1/embeddings` so other apps reuse it.
+//! This example wires `ServerConfig::embeddings_provider`: the endpoint
+//! accepts the standard request shape (`input` as a string or array of
+//! strings), batches calls to the provider, and returns the standard
+//! response with `index`, `embedding`, and usage. Without a configured
+//! provider the endpoint answers 501; over-long inputs get a 400 naming
+//! the limit.
+//!
+//! ```bash
+//! curl http://localhost:8080/v1/embeddings \
+//!   -d '{"model": "text-embedding-3-small", "input": ["hello", "world"]}'
+//! ```
+
+use helios_engine::rag::OpenAIEmbeddings;
+use helios_engine::serve::{self, ServerConfig};
+use helios_engine::{Agent, Config};
+
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - Embeddings Endpoint Example");
+    println!("==============================================\n");
+
+    let config = Config::from_file("config.toml")?;
+
+    let agent = Agent::builder("helios")
+        .config(config.clone())
+        .system_prompt("You are a helpful assistant.")
+        .build()
+        .await?;
+
+    let embeddings = OpenAIEmbeddings::new(
+        config.llm.base_url.clone(),
+        config.llm.api_key.clone(),
+    );
+
+    let server_config = ServerConfig::new()
+        // The same provider the RAG system uses; swap in a local provider
+        // when the `local` feature lands.
+        .embeddings_provider(Box::new(embeddings))
+        // Inputs beyond this many characters are rejected with a 400.
+        .max_embedding_input_chars(8192);
+
+    println!("Serving /v1/embeddings on http://localhost:8080");
+    println!("Point any OpenAI embeddings client at it.\n");
+
+    serve::start_server_with_agent_and_config(agent, "helios".to_string(), "127.0.0.1:8080", server_config)
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,61 @@
+//! # Example: Serving a Forest over the OpenAI-Compatible API
+//!
+//! `start_server_with_agent` exposes a single agent; this example
+//! demonstrates `serve::start_server_with_forest`, which puts a whole team
+//! behind `/v1/chat/completions`. Each request runs
+//! `execute_collaborative_task` with the user's last message as the task,
+//! using a configured coordinator and participant list. The final answer
+//! lands in the assistant message, the task breakdown rides along in a
+//! vendor extension field, and `stream: true` forwards forest streaming
+//! events as SSE chunks prefixed by agent name.
+//!
+//! Try it with any OpenAI client:
+//!
+//! ```bash
+//! curl http://localhost:8080/v1/chat/completions \
+//!   -H "Content-Type: application/json" \
+//!   -d '{"model": "helios-forest", "messages": [{"role": "user", "content": "Plan a product launch."}]}'
+//! ```
+
+use helios_engine::serve::{self, ForestServeOptions};
+use helios_engine::{Agent, Config, ForestBuilder};
+
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - Forest Server Example");
+    println!("========================================\n");
+
+    let config = Config::from_file("config.toml")?;
+
+    let forest = ForestBuilder::new()
+        .config(config)
+        .agent(
+            "coordinator".to_string(),
+            Agent::builder("coordinator").system_prompt("You plan and delegate."),
+        )
+        .agent(
+            "researcher".to_string(),
+            Agent::builder("researcher").system_prompt("You research."),
+        )
+        .agent(
+            "writer".to_string(),
+            Agent::builder("writer").system_prompt("You write."),
+        )
+        .build()
+        .await?;
+
+    let options = ForestServeOptions::new("coordinator")
+        .participants(vec!["researcher".to_string(), "writer".to_string()])
+        // Attach the per-task breakdown under "helios_tasks" in responses.
+        .include_task_breakdown(true)
+        // Clone the forest per request instead of queueing; flip to
+        // `queue_requests()` to serialize onto one shared instance.
+        .clone_per_request(true);
+
+    println!("Serving forest as model 'helios-forest' on http://localhost:8080");
+    println!("Streaming responses are prefixed by the emitting agent.\n");
+
+    serve::start_server_with_forest(forest, "helios-forest", "127.0.0.1:8080", options).await?;
+
+    Ok(())
+}
@@ -0,0 +1,67 @@
+//! # Example: Latency Budget-Aware Model Routing
+//!
+//! An SLA might require p95 under 3 seconds for short questions while other
+//! requests can tolerate 30 seconds for quality. This example demonstrates
+//! per-request routing in the server: a `latency_budget_ms` request field
+//! (or header) selects between configured model profiles, and a declarative
+//! `RoutingPolicy` adjusts agent settings per route — disabling reflection,
+//! lowering `max_iterations`, shrinking `max_tokens` for the fast lane.
+//!
+//! The chosen route and realized latency are recorded in metrics and the
+//! response's debug fields for SLA audits, and the server falls back to the
+//! quality route when the fast model's health check is failing.
+
+use helios_engine::serve::{RouteSettings, RoutingPolicy, ServeOptions};
+use helios_engine::{serve, Agent, Config};
+
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - Latency-Aware Routing Example");
+    println!("================================================\n");
+
+    let config = Config::from_file("config.toml")?;
+
+    let agent = Agent::builder("RoutedAgent")
+        .config(config.clone())
+        .system_prompt("You are a helpful assistant.")
+        .build()
+        .await?;
+
+    // Requests with a budget under 3000ms take the fast profile with
+    // trimmed settings; everything else takes the quality profile.
+    let policy = RoutingPolicy::new()
+        .route(
+            "fast",
+            RouteSettings::profile("gpt-4o-mini")
+                .max_latency_ms(3000)
+                .max_iterations(2)
+                .max_tokens(512)
+                .disable_reflection(),
+        )
+        .route(
+            "quality",
+            RouteSettings::profile("gpt-4o").max_iterations(8),
+        )
+        .default_route("quality")
+        // If the fast model's health check fails, budgeted requests fall
+        // back to quality rather than erroring.
+        .fallback("fast", "quality");
+
+    let options = ServeOptions::default().routing_policy(policy);
+
+    println!("Starting server on http://127.0.0.1:8000");
+    println!("Fast lane:    curl http://127.0.0.1:8000/v1/chat/completions \\");
+    println!("  -H 'Content-Type: application/json' \\");
+    println!("  -d '{{\"model\": \"auto\", \"latency_budget_ms\": 2000, \\");
+    println!("       \"messages\": [{{\"role\": \"user\", \"content\": \"Quick: capital of France?\"}}]}}'");
+    println!();
+    println!("Quality lane: omit latency_budget_ms (or send a large one).");
+    println!();
+    println!("Responses include debug fields:");
+    println!("  \"helios_route\": \"fast\", \"helios_latency_ms\": 812");
+
+    serve::start_server_with_agent_and_options(agent, "auto".to_string(), "127.0.0.1:8000", options)
+        .await?;
+
+    Ok(())
+}
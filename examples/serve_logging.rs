@@ -0,0 +1,51 @@
+//! # Example: Request Logging with Redaction
+//!
+//! Debugging the server is hard without seeing what clients send. This
+//! example enables structured request logging via tracing — method, path,
+//! model, message count, latency, status, token usage — plus the optional
+//! debug mode that writes full request/response bodies to a rotating file
+//! with configured fields and patterns redacted (API keys, anything
+//! matching `sk-[A-Za-z0-9]+`). Streaming responses are logged as the
+//! assembled final content once the stream completes.
+
+use helios_engine::serve::{self, LoggingConfig, ServerConfig};
+use helios_engine::{Agent, Config};
+
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    tracing_subscriber::fmt::init();
+
+    println!("🚀 Helios Engine - Server Logging Example");
+    println!("=========================================\n");
+
+    let config = Config::from_file("config.toml")?;
+
+    let agent = Agent::builder("helios")
+        .config(config)
+        .system_prompt("You are a helpful assistant.")
+        .build()
+        .await?;
+
+    let server_config = ServerConfig::new().logging(
+        LoggingConfig::new()
+            // One structured tracing event per request:
+            // method, path, model, message count, latency, status, tokens.
+            .structured(true)
+            // Full bodies to a rotating file — only with redaction on.
+            .debug_bodies("logs/helios-server.log")
+            .rotate_max_bytes(10 * 1024 * 1024)
+            // Field names blanked wherever they appear in bodies.
+            .redact_field("api_key")
+            .redact_field("authorization")
+            // Plus regex-based scrubbing for keys embedded in content.
+            .redact_pattern(r"sk-[A-Za-z0-9]+"),
+    );
+
+    println!("Serving with request logging on http://localhost:8080");
+    println!("Structured events go to tracing; bodies to logs/helios-server.log (redacted).\n");
+
+    serve::start_server_with_agent_and_config(agent, "helios".to_string(), "127.0.0.1:8080", server_config)
+        .await?;
+
+    Ok(())
+}
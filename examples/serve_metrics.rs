@@ -0,0 +1,52 @@
+//! # Example: Health, Readiness, and Prometheus Metrics
+//!
+//! Running the served agent in Kubernetes needs probes and metrics. This
+//! example enables the observability endpoints: `/healthz` answers 200 as
+//! soon as the server is up, `/readyz` answers 200 only once the model is
+//! reachable (local model loaded, or the remote provider answered a ping),
+//! and `/metrics` exposes Prometheus text format — request counts and
+//! latencies per endpoint, tokens in/out, tool invocation counts, active
+//! conversations, and error counts by type. Requires the `metrics`
+//! feature so minimal builds stay lean.
+//!
+//! ```bash
+//! curl http://localhost:8080/healthz
+//! curl http://localhost:8080/readyz
+//! curl http://localhost:8080/metrics
+//! # helios_requests_total{endpoint="/v1/chat/completions"} 42
+//! # helios_tokens_total{direction="out"} 18210
+//! ```
+
+use helios_engine::serve::{self, ServerConfig};
+use helios_engine::tools::CalculatorTool;
+use helios_engine::{Agent, Config};
+
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - Server Metrics Example");
+    println!("=========================================\n");
+
+    let config = Config::from_file("config.toml")?;
+
+    let agent = Agent::builder("helios")
+        .config(config)
+        .system_prompt("You are a helpful assistant.")
+        .tool(Box::new(CalculatorTool))
+        .build()
+        .await?;
+
+    let server_config = ServerConfig::new()
+        // /healthz and /readyz for liveness/readiness probes.
+        .health_endpoints(true)
+        // /metrics in Prometheus text format, fed by a lightweight
+        // internal registry updated from the agent loop and handlers.
+        .prometheus_metrics(true);
+
+    println!("Serving with probes and metrics on http://localhost:8080");
+    println!("Scrape /metrics; wire /healthz and /readyz into your probes.\n");
+
+    serve::start_server_with_agent_and_config(agent, "helios".to_string(), "127.0.0.1:8080", server_config)
+        .await?;
+
+    Ok(())
+}
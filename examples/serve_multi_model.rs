@@ -0,0 +1,65 @@
+//! # Example: Routing by Model Name
+//!
+//! One process can serve several differently-configured agents, picked via
+//! the `model` field exactly as OpenAI routes models. This example
+//! demonstrates `serve::start_server_with_agents`: `/v1/models` lists every
+//! registered name, `/v1/chat/completions` dispatches to the agent whose
+//! name matches `model`, and unknown models get a 404-style error body.
+//! Each agent has its own concurrency guard — requests for the same agent
+//! queue, while different agents run in parallel.
+//!
+//! ```bash
+//! curl http://localhost:8080/v1/models
+//! curl http://localhost:8080/v1/chat/completions \
+//!   -d '{"model": "concise", "messages": [{"role": "user", "content": "Explain DNS."}]}'
+//! ```
+
+use std::collections::HashMap;
+
+use helios_engine::{serve, Agent, Config};
+
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - Multi-Model Server Example");
+    println!("=============================================\n");
+
+    let config = Config::from_file("config.toml")?;
+
+    let mut agents = HashMap::new();
+
+    agents.insert(
+        "concise".to_string(),
+        Agent::builder("concise")
+            .config(config.clone())
+            .system_prompt("Answer in at most two sentences.")
+            .build()
+            .await?,
+    );
+
+    agents.insert(
+        "detailed".to_string(),
+        Agent::builder("detailed")
+            .config(config.clone())
+            .system_prompt("Give thorough, well-structured answers.")
+            .build()
+            .await?,
+    );
+
+    agents.insert(
+        "pirate".to_string(),
+        Agent::builder("pirate")
+            .config(config)
+            .system_prompt("Answer like a pirate.")
+            .build()
+            .await?,
+    );
+
+    println!("Serving {} models on http://localhost:8080", agents.len());
+    println!("GET /v1/models to list them; pick one with the 'model' field.\n");
+
+    // For routing rules beyond exact-name dispatch, see the
+    // serve_routing_rules example built on MultiAgentServer.
+    serve::start_server_with_agents(agents, "127.0.0.1:8080").await?;
+
+    Ok(())
+}
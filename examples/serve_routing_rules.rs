@@ -0,0 +1,78 @@
+//! # Example: Expression-Based Routing Rules
+//!
+//! When serving several agents, routing rules ("if the message mentions an
+//! invoice number pattern, route to billing-agent") should be editable by
+//! ops in config, not code. This example demonstrates the safe rule
+//! expression language for serve routing: predicates over the request —
+//! regex on content, header equality, model field, authenticated key
+//! attributes — combined with and/or/not, mapping to a target agent with
+//! optional ChatOptions overrides. Rules are validated at load/reload time
+//! with position-accurate errors and evaluated in order with a default
+//! route. A dry-run admin endpoint reports which rule matched and why.
+//!
+//! ## Example config.toml
+//!
+//! ```toml
+//! [[serve.routing_rules]]
+//! rule = 'content ~ "INV-[0-9]{6}" and header("x-tier") == "enterprise"'
+//! target = "billing-agent"
+//! max_iterations = 8
+//!
+//! [[serve.routing_rules]]
+//! rule = 'model == "fast" or content ~ "(?i)quick question"'
+//! target = "fast-agent"
+//!
+//! [serve.routing]
+//! default = "general-agent"
+//! ```
+
+use helios_engine::{serve, Agent, Config};
+
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - Routing Rules Example");
+    println!("========================================\n");
+
+    // Rule syntax errors fail the load with line/column positions, e.g.
+    // "serve.routing_rules[0].rule:23: expected ')' after predicate".
+    let config = Config::from_file("config.toml")?;
+
+    let billing = Agent::builder("billing-agent")
+        .config(config.clone())
+        .system_prompt("You handle invoices and payments.")
+        .build()
+        .await?;
+
+    let fast = Agent::builder("fast-agent")
+        .config(config.clone())
+        .system_prompt("You give short answers quickly.")
+        .build()
+        .await?;
+
+    let general = Agent::builder("general-agent")
+        .config(config.clone())
+        .system_prompt("You are a helpful general assistant.")
+        .build()
+        .await?;
+
+    println!("Starting server on http://127.0.0.1:8000\n");
+    println!("A message containing 'INV-004821' from an enterprise key routes");
+    println!("to billing-agent; everything else falls through in rule order to");
+    println!("the default route.\n");
+    println!("Dry-run a request against the rules without executing it:");
+    println!("  curl -X POST http://127.0.0.1:8000/admin/routing/dry-run \\");
+    println!("    -d '{{\"content\": \"Please check INV-004821\", \\");
+    println!("         \"headers\": {{\"x-tier\": \"enterprise\"}}}}'");
+    println!("  → {{\"matched_rule\": 0, \"target\": \"billing-agent\",");
+    println!("     \"explanation\": \"content ~ INV-[0-9]{{6}} matched at 13..23\"}}");
+
+    serve::MultiAgentServer::new()
+        .agent("billing-agent", billing)
+        .agent("fast-agent", fast)
+        .agent("general-agent", general)
+        .routing_from_config(&config)
+        .serve("127.0.0.1:8000")
+        .await?;
+
+    Ok(())
+}
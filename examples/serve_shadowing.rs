@@ -0,0 +1,65 @@
+//! # Example: Request Shadowing
+//!
+//! Before switching a served agent to a new model, shadow a fraction of real
+//! traffic against the candidate and compare outputs offline — without
+//! affecting user responses. For sampled requests, the shadow layer runs the
+//! same input through a second agent *after* the primary response has been
+//! sent, never blocking the primary path, and records paired results (both
+//! outputs, latencies, usage, diff summary) under a shared correlation id
+//! for later analysis. The shadow path has its own capped budget and rate
+//! limits.
+
+use helios_engine::serve::{ServeOptions, ShadowConfig};
+use helios_engine::{serve, Agent, Config};
+
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - Request Shadowing Example");
+    println!("============================================\n");
+
+    let config = Config::from_file("config.toml")?;
+
+    // The primary agent serves production traffic exactly as before.
+    let primary = Agent::builder("Primary")
+        .config(config.clone())
+        .system_prompt("You are a helpful assistant.")
+        .build()
+        .await?;
+
+    // The candidate configuration we want to evaluate.
+    let mut candidate_config = config.clone();
+    candidate_config.llm.model_name = "candidate-model".to_string();
+
+    let candidate = Agent::builder("Candidate")
+        .config(candidate_config)
+        .system_prompt("You are a helpful assistant.")
+        .build()
+        .await?;
+
+    // Shadow 10% of requests; the shadow path gets at most 100 runs per
+    // hour and its own token budget so an expensive candidate can't grow
+    // the bill unboundedly.
+    let shadow = ShadowConfig::new(candidate)
+        .sample_rate(0.10)
+        .max_runs_per_hour(100)
+        .record_to("shadow_pairs.jsonl");
+
+    let options = ServeOptions::default().shadow(shadow);
+
+    println!("Starting server on http://127.0.0.1:8000");
+    println!("10% of requests will be mirrored to the candidate after the");
+    println!("primary response is sent. Paired results accumulate in");
+    println!("shadow_pairs.jsonl with matching correlation ids.\n");
+    println!("Generate the comparison report afterwards with:");
+    println!("  helios_engine::serve::shadow_report(\"shadow_pairs.jsonl\")");
+
+    serve::start_server_with_agent_and_options(
+        primary,
+        "production-model".to_string(),
+        "127.0.0.1:8000",
+        options,
+    )
+    .await?;
+
+    Ok(())
+}
@@ -0,0 +1,50 @@
+//! # Example: Graceful Server Shutdown
+//!
+//! A SIGTERM used to kill requests mid-generation. This example
+//! demonstrates the shutdown handle: `serve::spawn_server_with_agent`
+//! returns a `ServerHandle` whose `shutdown()` stops accepting new
+//! connections, waits up to a grace period for in-flight requests
+//! (including streams) to finish, then aborts the rest with 503s. Ctrl-C
+//! and SIGTERM trigger the same path by default, persisted sessions are
+//! flushed during the drain, and the start function returns once draining
+//! completes so callers can run their own cleanup.
+
+use std::time::Duration;
+
+use helios_engine::serve::{self, ServeOptions};
+use helios_engine::{Agent, Config};
+
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - Graceful Shutdown Example");
+    println!("============================================\n");
+
+    let config = Config::from_file("config.toml")?;
+
+    let agent = Agent::builder("helios")
+        .config(config)
+        .system_prompt("You are a helpful assistant.")
+        .build()
+        .await?;
+
+    let options = ServeOptions::default()
+        // In-flight requests get this long to finish before 503s.
+        .shutdown_grace_period(Duration::from_secs(20));
+
+    let handle =
+        serve::spawn_server_with_agent(agent, "helios".to_string(), "127.0.0.1:8080", options)
+            .await?;
+    println!("Serving on http://localhost:8080 (Ctrl-C drains and exits)\n");
+
+    // Simulate an operator-triggered shutdown after a while; Ctrl-C or
+    // SIGTERM would do exactly the same drain.
+    tokio::time::sleep(Duration::from_secs(60)).await;
+    println!("Shutting down: no new connections, draining in-flight requests...");
+    handle.shutdown().await?;
+
+    // We only get here once the drain is complete and sessions are
+    // flushed, so cleanup below runs with no requests in flight.
+    println!("✓ Drained. Running post-shutdown cleanup.");
+
+    Ok(())
+}
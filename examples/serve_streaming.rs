@@ -0,0 +1,51 @@
+//! # Example: SSE Streaming from the Server
+//!
+//! Clients sending `"stream": true` expect Server-Sent Events, not a
+//! buffered response. This example starts a server with streaming enabled:
+//! the `/v1/chat/completions` endpoint emits `chat.completion.chunk`
+//! objects with `delta.content` as the agent generates, a final chunk with
+//! `finish_reason`, and `data: [DONE]`. Tool-call iterations inside the
+//! agent surface as additional chunks, and a client disconnect cancels the
+//! in-flight generation.
+//!
+//! Point any OpenAI SDK at it:
+//!
+//! ```bash
+//! curl -N http://localhost:8080/v1/chat/completions \
+//!   -H "Content-Type: application/json" \
+//!   -d '{"model": "helios", "stream": true, "messages": [{"role": "user", "content": "Count to ten slowly."}]}'
+//! ```
+
+use helios_engine::serve::{self, ServeOptions};
+use helios_engine::tools::CalculatorTool;
+use helios_engine::{Agent, Config};
+
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - SSE Streaming Example");
+    println!("========================================\n");
+
+    let config = Config::from_file("config.toml")?;
+
+    let agent = Agent::builder("helios")
+        .config(config)
+        .system_prompt("You are a helpful assistant.")
+        // Tool calls mid-generation are reflected as extra chunks rather
+        // than breaking the stream.
+        .tool(Box::new(CalculatorTool))
+        .build()
+        .await?;
+
+    let options = ServeOptions::default()
+        .streaming(true)
+        // Stop generating the moment the client goes away.
+        .cancel_on_disconnect(true);
+
+    println!("Serving with SSE streaming on http://localhost:8080");
+    println!("Send \"stream\": true to receive chat.completion.chunk events.\n");
+
+    serve::start_server_with_agent_and_options(agent, "helios".to_string(), "127.0.0.1:8080", options)
+        .await?;
+
+    Ok(())
+}
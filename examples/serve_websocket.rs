@@ -0,0 +1,59 @@
+//! # Example: WebSocket Chat Endpoint
+//!
+//! SSE is one-directional; interactive web UIs want a socket. This example
+//! enables the `/ws/chat` WebSocket endpoint: the client sends JSON
+//! messages like `{"type": "user_message", "content": "..."}` and receives
+//! streamed token events, tool-call notifications, and a final completion
+//! event — one `ChatSession` per socket. Ping/pong keepalive and an idle
+//! timeout are handled for you, `{"type": "cancel"}` aborts the current
+//! generation via the cancellation token, and a `{"type": "hello"}`
+//! handshake returns the full message schema.
+//!
+//! Client-side sketch:
+//!
+//! ```js
+//! const ws = new WebSocket("ws://localhost:8080/ws/chat");
+//! ws.onopen = () => ws.send(JSON.stringify({ type: "hello" }));
+//! ws.onmessage = (e) => {
+//!   const msg = JSON.parse(e.data);
+//!   // msg.type: "schema" | "token" | "tool_call" | "completion" | "error"
+//! };
+//! ws.send(JSON.stringify({ type: "user_message", content: "Hi!" }));
+//! // Later: ws.send(JSON.stringify({ type: "cancel" }));
+//! ```
+
+use std::time::Duration;
+
+use helios_engine::serve::{self, ServeOptions, WebSocketConfig};
+use helios_engine::tools::CalculatorTool;
+use helios_engine::{Agent, Config};
+
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - WebSocket Chat Example");
+    println!("=========================================\n");
+
+    let config = Config::from_file("config.toml")?;
+
+    let agent = Agent::builder("helios")
+        .config(config)
+        .system_prompt("You are a helpful assistant.")
+        .tool(Box::new(CalculatorTool))
+        .build()
+        .await?;
+
+    let options = ServeOptions::default().websocket(
+        WebSocketConfig::new("/ws/chat")
+            .ping_interval(Duration::from_secs(30))
+            // Sockets silent for this long are closed server-side.
+            .idle_timeout(Duration::from_secs(5 * 60)),
+    );
+
+    println!("WebSocket chat at ws://localhost:8080/ws/chat");
+    println!("Send {{\"type\": \"hello\"}} for the message schema.\n");
+
+    serve::start_server_with_agent_and_options(agent, "helios".to_string(), "127.0.0.1:8080", options)
+        .await?;
+
+    Ok(())
+}
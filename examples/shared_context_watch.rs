@@ -0,0 +1,87 @@
+//! # Example: Watching Shared Context Keys
+//!
+//! Agents that poll shared context in a loop ("has the researcher posted
+//! findings yet?") waste iterations. This example demonstrates the watch
+//! mechanism on `SharedContext`: `watch_key` returns a receiver that fires
+//! on writes to a key or prefix, `wait_for_key` is the async helper with a
+//! timeout, and the `wait_for_shared_data` tool lets an agent block its task
+//! until a dependency key appears — the executor treats the blocked task as
+//! idle and still enforces the task timeout.
+
+use std::time::Duration;
+
+use helios_engine::{Agent, Config, ForestBuilder, SharedContext};
+
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - Shared Context Watch Example");
+    println!("===============================================\n");
+
+    // --- Example 1: watch_key and wait_for_key directly ---
+    println!("Example 1: Direct Watching");
+    println!("==========================\n");
+
+    let context = SharedContext::new();
+
+    // Prefix watch: fires for findings:raw, findings:summary, ...
+    let mut rx = context.watch_key("findings:");
+
+    let writer = {
+        let context = context.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(200)).await;
+            context.set("findings:raw", "42 datapoints collected").await;
+        })
+    };
+
+    let change = rx.recv().await.unwrap();
+    println!("notified: {} = {}", change.key, change.value);
+    writer.await.ok();
+
+    // The one-shot helper with a timeout:
+    match context.wait_for_key("findings:summary", Duration::from_millis(300)).await {
+        Ok(value) => println!("got summary: {}", value),
+        Err(_) => println!("wait_for_key timed out as expected\n"),
+    }
+
+    // --- Example 2: Forest agents blocking instead of polling ---
+    println!("Example 2: wait_for_shared_data Tool");
+    println!("====================================\n");
+
+    let config = Config::from_file("config.toml")?;
+
+    let mut forest = ForestBuilder::new()
+        .config(config)
+        .agent(
+            "coordinator".to_string(),
+            Agent::builder("coordinator").system_prompt("You coordinate research and writing."),
+        )
+        .agent(
+            "researcher".to_string(),
+            Agent::builder("researcher")
+                .system_prompt("Research the topic, then write your findings to shared context under 'findings'."),
+        )
+        .agent(
+            "writer".to_string(),
+            Agent::builder("writer")
+                .system_prompt(
+                    "Use wait_for_shared_data to wait for the 'findings' key \
+                     (timeout 120s), then write the summary. Do not poll.",
+                ),
+        )
+        .build()
+        .await?;
+
+    // The writer's task blocks in wait_for_shared_data without burning LLM
+    // iterations; the executor schedules other work meanwhile.
+    let result = forest
+        .execute_collaborative_task(
+            &"coordinator".to_string(),
+            "Research solar adoption trends and produce a one-paragraph summary.".to_string(),
+            vec!["researcher".to_string(), "writer".to_string()],
+        )
+        .await?;
+    println!("Result: {}", result);
+
+    Ok(())
+}
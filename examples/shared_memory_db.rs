@@ -0,0 +1,98 @@
+//! # Example: Shared and Namespaced MemoryDB
+//!
+//! When several forest agents each get their own `MemoryDBTool::new()`,
+//! they can't see each other's data. This example demonstrates
+//! `MemoryDBTool::shared()` — a clonable handle over one underlying store,
+//! safe to register on multiple agents — and the `namespace` constructor
+//! argument that prefixes keys so agents get private and shared areas.
+//! `ForestBuilder::shared_memory_db()` wires a shared instance into every
+//! agent automatically.
+
+use helios_engine::{Agent, Config, ForestBuilder, MemoryDBTool};
+
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - Shared MemoryDB Example");
+    println!("==========================================\n");
+
+    let config = Config::from_file("config.toml")?;
+
+    // --- Example 1: One store, two standalone agents ---
+    println!("Example 1: Shared Handle");
+    println!("========================\n");
+
+    let shared = MemoryDBTool::shared();
+
+    let mut alice = Agent::builder("alice")
+        .config(config.clone())
+        .system_prompt("You store findings in the memory_db tool.")
+        .tool(Box::new(shared.clone()))
+        .build()
+        .await?;
+
+    let mut bob = Agent::builder("bob")
+        .config(config.clone())
+        .system_prompt("You recall data from the memory_db tool.")
+        .tool(Box::new(shared.clone()))
+        .build()
+        .await?;
+
+    alice
+        .chat("Store that the release date is September 3rd.")
+        .await?;
+    let response = bob.chat("What is the release date?").await?;
+    println!("Bob: {}\n", response);
+
+    // --- Example 2: Namespaces for private vs shared areas ---
+    println!("Example 2: Namespaces");
+    println!("=====================\n");
+
+    // Keys written through this handle are prefixed "alice:"; list can
+    // filter by namespace so agents don't trample each other.
+    let alice_private = shared.clone().namespace("alice");
+    let team_area = shared.clone().namespace("team");
+
+    let mut scoped_agent = Agent::builder("alice")
+        .config(config.clone())
+        .system_prompt("Use memory_db_private for your notes, memory_db_team for shared findings.")
+        .tool(Box::new(alice_private))
+        .tool(Box::new(team_area))
+        .build()
+        .await?;
+
+    scoped_agent
+        .chat("Privately note: my draft needs a second pass. For the team: testing is done.")
+        .await?;
+    println!("✓ Private and team entries written under their namespaces\n");
+
+    // --- Example 3: Forest wiring ---
+    println!("Example 3: ForestBuilder::shared_memory_db");
+    println!("==========================================\n");
+
+    let mut forest = ForestBuilder::new()
+        .config(config)
+        .agent(
+            "coordinator".to_string(),
+            Agent::builder("coordinator").system_prompt("You coordinate and check shared memory."),
+        )
+        .agent(
+            "worker".to_string(),
+            Agent::builder("worker").system_prompt("You record results in shared memory."),
+        )
+        // Every agent gets a handle to the same store, namespaced by agent
+        // name, plus a common "shared" area.
+        .shared_memory_db()
+        .build()
+        .await?;
+
+    let result = forest
+        .execute_collaborative_task(
+            &"coordinator".to_string(),
+            "Worker: record today's status as 'green'. Coordinator: read it back.".to_string(),
+            vec!["worker".to_string()],
+        )
+        .await?;
+    println!("Result: {}", result);
+
+    Ok(())
+}
@@ -0,0 +1,75 @@
+//! # Example: Speculative Tool Prefetching
+//!
+//! A large share of agent latency is the second LLM round-trip after an
+//! obvious tool call — when the user asks "what's in config.toml", the file
+//! read was always going to happen. This example demonstrates the opt-in
+//! speculation hook: a host-provided predictor inspects the `ChatSession`
+//! and nominates (tool, args) pairs that run concurrently with the first LLM
+//! call. If the model then requests a matching call (same tool plus
+//! canonicalized args), the prefetched result is used instantly; otherwise
+//! speculative results are discarded and never enter the session.
+//!
+//! Only tools marked non-mutating are eligible, speculative executions are
+//! budgeted separately, and the audit log flags hits and misses.
+
+use helios_engine::agent::SpeculationPredictor;
+use helios_engine::{Agent, Config, FileReadTool, FileSearchTool};
+
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - Speculative Prefetch Example");
+    println!("===============================================\n");
+
+    let config = Config::from_file("config.toml")?;
+
+    // The predictor: if the latest user message mentions a file name, kick
+    // off a read for it while the LLM is still thinking.
+    let predictor: SpeculationPredictor = Box::new(|session| {
+        let Some(last) = session.last_user_message() else {
+            return vec![];
+        };
+        last.content
+            .split_whitespace()
+            .filter(|word| word.ends_with(".toml") || word.ends_with(".json"))
+            .map(|file| {
+                (
+                    "file_read".to_string(),
+                    serde_json::json!({ "path": file }),
+                )
+            })
+            .collect()
+    });
+
+    let mut agent = Agent::builder("FastReader")
+        .config(config)
+        .system_prompt("You answer questions about files using your tools.")
+        // Both file tools are read-only and idempotent, so they are marked
+        // eligible for speculation.
+        .tool(Box::new(FileReadTool))
+        .tool(Box::new(FileSearchTool))
+        .speculation(predictor)
+        .build()
+        .await?;
+
+    // --- A predictable question: the prefetch should hit ---
+    println!("Question: what's in config.toml?\n");
+    let start = std::time::Instant::now();
+    let response = agent.chat("What's in config.toml?").await?;
+    println!("Agent ({:?}): {}\n", start.elapsed(), response);
+
+    // --- An unrelated question: the speculation misses and is discarded ---
+    println!("Question: how do I exit vim?\n");
+    let response = agent.chat("How do I exit vim?").await?;
+    println!("Agent: {}\n", response);
+
+    // --- Measure the win ---
+    println!("Speculation Stats");
+    println!("=================\n");
+
+    let stats = agent.speculation_stats();
+    println!("speculative executions: {}", stats.executions);
+    println!("hits:                   {}", stats.hits);
+    println!("misses (discarded):     {}", stats.misses);
+
+    Ok(())
+}
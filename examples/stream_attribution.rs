@@ -0,0 +1,87 @@
+//! # Example: Attributed Streaming in a Forest
+//!
+//! When every agent streams to stdout you can't tell whose tokens you're
+//! reading. This example demonstrates attributed streaming: chunks arrive
+//! through the event API as `ForestEvent::AgentStreamChunk { agent,
+//! task_id, chunk }` instead of being printed inside the library, so a UI
+//! can render each speaker separately. A default stdout renderer is
+//! provided that prints a colored prefix whenever the speaking agent
+//! changes.
+
+use helios_engine::forest::{stdout_stream_renderer, ForestEvent};
+use helios_engine::{Agent, Config, ForestBuilder};
+
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - Stream Attribution Example");
+    println!("=============================================\n");
+
+    let config = Config::from_file("config.toml")?;
+
+    // --- Example 1: Custom handling of attributed chunks ---
+    println!("Example 1: Custom Chunk Handler");
+    println!("===============================\n");
+
+    let mut forest = ForestBuilder::new()
+        .config(config.clone())
+        .agent(
+            "coordinator".to_string(),
+            Agent::builder("coordinator").system_prompt("You plan and delegate."),
+        )
+        .agent(
+            "analyst".to_string(),
+            Agent::builder("analyst").system_prompt("You analyze."),
+        )
+        .agent(
+            "writer".to_string(),
+            Agent::builder("writer").system_prompt("You write."),
+        )
+        .on_event(|event| {
+            if let ForestEvent::AgentStreamChunk { agent, task_id, chunk } = event {
+                // A UI would route this to the panel for `agent`; here we
+                // just tag each chunk so the interleaving is visible.
+                print!("[{}:{}] {}", agent, task_id, chunk);
+            }
+        })
+        .build()
+        .await?;
+
+    let result = forest
+        .execute_collaborative_task(
+            &"coordinator".to_string(),
+            "Compare SQLite and DuckDB in three short paragraphs.".to_string(),
+            vec!["analyst".to_string(), "writer".to_string()],
+        )
+        .await?;
+    println!("\n\nFinal: {}\n", result);
+
+    // --- Example 2: The built-in renderer ---
+    println!("Example 2: Default Stdout Renderer");
+    println!("==================================\n");
+
+    let mut forest = ForestBuilder::new()
+        .config(config)
+        .agent(
+            "coordinator".to_string(),
+            Agent::builder("coordinator").system_prompt("You plan and delegate."),
+        )
+        .agent(
+            "poet".to_string(),
+            Agent::builder("poet").system_prompt("You answer in verse."),
+        )
+        // Prints a colored "agent ▸" prefix each time the speaker changes;
+        // the library itself no longer prints anything.
+        .on_event(stdout_stream_renderer())
+        .build()
+        .await?;
+
+    forest
+        .execute_collaborative_task(
+            &"coordinator".to_string(),
+            "Write a haiku about concurrency.".to_string(),
+            vec!["poet".to_string()],
+        )
+        .await?;
+
+    Ok(())
+}
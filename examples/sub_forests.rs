@@ -0,0 +1,87 @@
+//! # Example: Sub-Forests
+//!
+//! Big projects want hierarchical decomposition: the coordinator assigns
+//! "build the data section" to a lead agent who internally runs its own
+//! small team. This example demonstrates `Forest::nest(agent_id,
+//! ForestBuilder)`: the lead agent gets a tool that runs a nested
+//! collaborative task with its own plan and shared context, reports the
+//! final answer and a sub-task summary back under a namespaced key in the
+//! parent's shared memory, and rolls token/cost totals up into the parent.
+//! Nesting depth is bounded to keep recursion in check.
+
+use helios_engine::{Agent, Config, ForestBuilder};
+
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - Sub-Forests Example");
+    println!("======================================\n");
+
+    let config = Config::from_file("config.toml")?;
+
+    // The nested team the data lead will run internally.
+    let data_team = ForestBuilder::new()
+        .config(config.clone())
+        .agent(
+            "data_lead".to_string(),
+            Agent::builder("data_lead").system_prompt("You coordinate data work."),
+        )
+        .agent(
+            "collector".to_string(),
+            Agent::builder("collector").system_prompt("You gather figures."),
+        )
+        .agent(
+            "charter".to_string(),
+            Agent::builder("charter").system_prompt("You describe charts."),
+        );
+
+    let mut forest = ForestBuilder::new()
+        .config(config)
+        .agent(
+            "coordinator".to_string(),
+            Agent::builder("coordinator").system_prompt(
+                "You plan the report. Delegate the data section to data_lead.",
+            ),
+        )
+        .agent(
+            "data_lead".to_string(),
+            Agent::builder("data_lead")
+                .system_prompt("Run your sub-team to produce the data section."),
+        )
+        .agent(
+            "editor".to_string(),
+            Agent::builder("editor").system_prompt("You assemble and polish."),
+        )
+        // data_lead's tasks may spawn this nested forest; one level only.
+        .nest("data_lead", data_team)
+        .max_nesting_depth(1)
+        .build()
+        .await?;
+
+    let result = forest
+        .execute_collaborative_task_detailed(
+            &"coordinator".to_string(),
+            "Write a quarterly report with a data-backed section.".to_string(),
+            vec!["data_lead".to_string(), "editor".to_string()],
+        )
+        .await?;
+
+    println!("Final Answer");
+    println!("============\n");
+    println!("{}\n", result.final_answer);
+
+    // --- What the nested run left behind ---
+    println!("Sub-Forest Summary");
+    println!("==================\n");
+
+    // The nested run writes under subforest:<agent>:* in the parent context.
+    if let Some(summary) = forest.shared_context().get("subforest:data_lead:summary").await {
+        println!("{}\n", summary);
+    }
+
+    // Token and cost accounting from the sub-forest rolls up into the
+    // parent's totals, so the report covers the whole hierarchy.
+    let report = forest.cost_report();
+    println!("Grand total (incl. sub-forest): ${:.4}", report.total);
+
+    Ok(())
+}
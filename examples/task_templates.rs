@@ -0,0 +1,104 @@
+//! # Example: Reusable Plan Task Templates
+//!
+//! A coordinator that regenerates essentially the same plan every day for a
+//! daily-report workflow will occasionally get it wrong. This example
+//! demonstrates `TaskTemplate`s: named task definitions with a description
+//! template, default assignee, declared input keys (expected in shared
+//! context) and output keys (promised to be written), registered on the
+//! forest. Coordinators reference templates by name in create_plan — the
+//! tool expands and validates them — and host code can instantiate whole
+//! plans from templates with variable bindings. Validation catches wiring
+//! mistakes (an input no upstream task produces) before execution.
+
+use helios_engine::forest::TaskTemplate;
+use helios_engine::{Agent, Config, ForestBuilder};
+
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - Task Templates Example");
+    println!("=========================================\n");
+
+    let config = Config::from_file("config.toml")?;
+
+    // Templates declare their data contract up front.
+    let research = TaskTemplate::new("research")
+        .description("Collect today's {{topic}} figures from shared sources.")
+        .default_assignee("researcher")
+        .outputs(&["raw_figures"]);
+
+    let analyze = TaskTemplate::new("analyze")
+        .description("Analyze the raw figures and compute day-over-day deltas.")
+        .default_assignee("analyst")
+        .inputs(&["raw_figures"])
+        .outputs(&["analysis"]);
+
+    let write_report = TaskTemplate::new("write_report")
+        .description("Write the daily {{topic}} report from the analysis.")
+        .default_assignee("writer")
+        .inputs(&["analysis"])
+        .outputs(&["report"]);
+
+    let mut forest = ForestBuilder::new()
+        .config(config)
+        .agent(
+            "coordinator".to_string(),
+            Agent::builder("coordinator")
+                .system_prompt("Plan using the registered task templates by name."),
+        )
+        .agent(
+            "researcher".to_string(),
+            Agent::builder("researcher").system_prompt("You collect data."),
+        )
+        .agent(
+            "analyst".to_string(),
+            Agent::builder("analyst").system_prompt("You analyze data."),
+        )
+        .agent(
+            "writer".to_string(),
+            Agent::builder("writer").system_prompt("You write reports."),
+        )
+        .task_template(research)
+        .task_template(analyze)
+        .task_template(write_report)
+        .build()
+        .await?;
+
+    // --- Example 1: Host-instantiated plan from templates ---
+    println!("Example 1: Instantiate a Plan Directly");
+    println!("======================================\n");
+
+    let plan = forest.plan_from_templates(
+        &["research", "analyze", "write_report"],
+        serde_json::json!({ "topic": "sales" }),
+    )?;
+    println!("✓ Plan built: {} tasks, dependencies inferred from input/output keys\n", plan.tasks.len());
+
+    // Wiring mistakes fail fast: "analyze" without "research" means
+    // raw_figures is never produced.
+    match forest.plan_from_templates(&["analyze", "write_report"], serde_json::json!({})) {
+        Err(e) => println!("✓ missing-input detection: {}\n", e),
+        Ok(_) => println!("unexpected success\n"),
+    }
+
+    // --- Example 2: Coordinator references templates in create_plan ---
+    println!("Example 2: Coordinator Using Templates");
+    println!("======================================\n");
+
+    // The coordinator's create_plan call can mix templated tasks
+    // ({"template": "research", "vars": {...}}) with free-form ones; the
+    // tool expands and validates them together.
+    let result = forest
+        .execute_collaborative_task(
+            &"coordinator".to_string(),
+            "Produce today's sales report using the standard workflow.".to_string(),
+            vec![
+                "researcher".to_string(),
+                "analyst".to_string(),
+                "writer".to_string(),
+            ],
+        )
+        .await?;
+    println!("Result: {}", result);
+
+    Ok(())
+}
@@ -0,0 +1,76 @@
+//! # Example: Per-Task Timeouts
+//!
+//! A single stuck task (a slow model, an endless tool loop) can stall an
+//! entire collaborative run. This example demonstrates
+//! `ForestBuilder::task_timeout(Duration)` — a wall-clock budget applied to
+//! every task — plus per-task overrides in the plan via the optional
+//! `timeout_secs` field. Timed-out tasks get `TaskStatus::TimedOut`, the
+//! in-flight LLM call is cancelled, and a retry/escalation policy decides
+//! what happens next.
+
+use std::time::Duration;
+
+use helios_engine::forest::TimeoutPolicy;
+use helios_engine::{Agent, Config, ForestBuilder};
+
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - Task Timeout Example");
+    println!("=======================================\n");
+
+    let config = Config::from_file("config.toml")?;
+
+    let mut forest = ForestBuilder::new()
+        .config(config)
+        .agent(
+            "coordinator".to_string(),
+            Agent::builder("coordinator").system_prompt(
+                "You plan and delegate. You may set timeout_secs on tasks \
+                 you expect to be slow.",
+            ),
+        )
+        .agent(
+            "researcher".to_string(),
+            Agent::builder("researcher").system_prompt("You research thoroughly."),
+        )
+        .agent(
+            "writer".to_string(),
+            Agent::builder("writer").system_prompt("You write concisely."),
+        )
+        // Default wall-clock budget for every task in a plan.
+        .task_timeout(Duration::from_secs(120))
+        // On timeout: retry once with a fresh agent turn, then escalate the
+        // failure to the coordinator instead of aborting the whole run.
+        .timeout_policy(TimeoutPolicy::RetryThenEscalate { retries: 1 })
+        .build()
+        .await?;
+
+    let result = forest
+        .execute_collaborative_task(
+            &"coordinator".to_string(),
+            "Write a two-paragraph overview of WebAssembly runtimes.".to_string(),
+            vec!["researcher".to_string(), "writer".to_string()],
+        )
+        .await?;
+
+    println!("Result: {}\n", result);
+
+    // --- Inspect how the budget was spent ---
+    println!("Task Statuses");
+    println!("=============\n");
+    if let Some(plan) = forest.current_plan() {
+        for task in &plan.tasks {
+            // Tasks that exhausted their budget and their retries show as
+            // TaskStatus::TimedOut with the elapsed wall-clock time.
+            println!(
+                "{:<10} {:<12} {:?} (budget: {}s)",
+                task.id,
+                task.assigned_to,
+                task.status,
+                task.timeout_secs.unwrap_or(120)
+            );
+        }
+    }
+
+    Ok(())
+}
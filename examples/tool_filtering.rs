@@ -0,0 +1,84 @@
+//! # Example: Tool Groups and Per-Chat Tool Filtering
+//!
+//! When an agent has 15+ tools the schema gets huge and the model picks
+//! badly. This example demonstrates two features that keep the tool surface
+//! small per turn:
+//!
+//! 1. **Tool groups** — register related tools under a named group with
+//!    `AgentBuilder::tool_group("files", vec![...])`.
+//! 2. **Per-call filtering** — `Agent::chat_with_tools` takes a `ToolFilter`
+//!    so only a subset of tools is sent to the LLM for that turn. The filter
+//!    also gates execution: a filtered-out tool call returns an error result
+//!    rather than running.
+
+use helios_engine::agent::ToolFilter;
+use helios_engine::{
+    Agent, CalculatorTool, Config, EchoTool, FileReadTool, FileSearchTool, FileWriteTool,
+};
+
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - Tool Filtering Example");
+    println!("=========================================\n");
+
+    let config = Config::from_file("config.toml")?;
+
+    // Register tools in named groups so they can be filtered together.
+    let mut agent = Agent::builder("MultiToolAgent")
+        .config(config)
+        .system_prompt("You are a helpful assistant with many tools.")
+        .tool_group(
+            "files",
+            vec![
+                Box::new(FileSearchTool),
+                Box::new(FileReadTool),
+                Box::new(FileWriteTool),
+            ],
+        )
+        .tool_group("math", vec![Box::new(CalculatorTool)])
+        .tool(Box::new(EchoTool))
+        .build()
+        .await?;
+
+    // --- Example 1: Only the file tools for this turn ---
+    println!("Example 1: ToolFilter::Only");
+    println!("===========================\n");
+
+    let response = agent
+        .chat_with_tools(
+            "List the Rust files in the src directory.",
+            ToolFilter::Only(vec!["file_search".into(), "file_read".into()]),
+        )
+        .await?;
+    println!("Agent: {}\n", response);
+
+    // --- Example 2: Everything except the write-capable tools ---
+    println!("Example 2: ToolFilter::Except");
+    println!("=============================\n");
+
+    let response = agent
+        .chat_with_tools(
+            "What is 144 / 12?",
+            ToolFilter::Except(vec!["file_write".into()]),
+        )
+        .await?;
+    println!("Agent: {}\n", response);
+
+    // --- Example 3: Filter by group ---
+    println!("Example 3: ToolFilter::Group");
+    println!("============================\n");
+
+    let response = agent
+        .chat_with_tools(
+            "Compute (3.5 + 1.5) * 4 for me.",
+            ToolFilter::Group("math".into()),
+        )
+        .await?;
+    println!("Agent: {}\n", response);
+
+    // A normal `chat()` call still sees the full tool set.
+    let response = agent.chat("Echo back the word 'unfiltered'.").await?;
+    println!("Agent (unfiltered): {}", response);
+
+    Ok(())
+}
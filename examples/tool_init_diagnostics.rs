@@ -0,0 +1,76 @@
+//! # Example: Tool Initialization Diagnostics
+//!
+//! Agents with many tools (Qdrant, sqlite, MCP servers) either fail at
+//! build time on the first unavailable dependency or fail late
+//! mid-conversation — both painful. This example demonstrates the tool
+//! initialization phase in `AgentBuilder::build`: each tool can implement
+//! `initialize()` (connectivity checks, collection creation, version
+//! probes), the checks run concurrently with a timeout, and the build
+//! produces a structured diagnostics report. A policy decides whether an
+//! unavailable tool fails the build, is registered disabled with a reason
+//! the model can see, or retries lazily on first use.
+
+use std::time::Duration;
+
+use helios_engine::agent::ToolInitPolicy;
+use helios_engine::{Agent, CalculatorTool, Config, MemoryDBTool, QdrantRAGTool, SqlTool};
+
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - Tool Init Diagnostics Example");
+    println!("================================================\n");
+
+    let config = Config::from_file("config.toml")?;
+
+    // Qdrant may or may not be running; the SQL database may be down. With
+    // DisableUnavailable, the agent still builds — broken tools are
+    // registered as disabled, and the model sees why if it tries them.
+    let build = Agent::builder("ResilientAgent")
+        .config(config)
+        .system_prompt("Use whichever of your tools are available.")
+        .tool(Box::new(CalculatorTool))
+        .tool(Box::new(MemoryDBTool::new()))
+        .tool(Box::new(QdrantRAGTool::new(
+            "http://localhost:6333",
+            "kb",
+            "https://api.openai.com/v1/embeddings",
+            std::env::var("OPENAI_API_KEY").unwrap_or_default(),
+        )))
+        .tool(Box::new(SqlTool::lazy("postgres://localhost/reports", true)))
+        .tool_init_policy(ToolInitPolicy::DisableUnavailable)
+        .tool_init_timeout(Duration::from_secs(5))
+        .build_with_diagnostics()
+        .await?;
+
+    let mut agent = build.agent;
+
+    // --- The diagnostics report ---
+    println!("Build Diagnostics");
+    println!("=================\n");
+
+    for entry in &build.diagnostics {
+        println!(
+            "{:<12} {:<10} {}",
+            entry.tool_name,
+            entry.status, // Healthy | Disabled | Lazy
+            entry.detail.as_deref().unwrap_or("")
+        );
+    }
+
+    // The same information stays live on the agent and feeds the serve
+    // module's /readyz aggregation.
+    println!("\ntool_health(): {:?}\n", agent.tool_health());
+
+    // A disabled tool surfaces its reason to the model instead of crashing
+    // the conversation.
+    let response = agent
+        .chat("Search the knowledge base for the word 'onboarding'.")
+        .await?;
+    println!("Agent: {}\n", response);
+
+    // Other policies:
+    //   ToolInitPolicy::FailBuild      — strict: any unhealthy tool aborts build
+    //   ToolInitPolicy::LazyRetry      — try again on first use
+
+    Ok(())
+}
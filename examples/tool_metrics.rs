@@ -0,0 +1,64 @@
+//! # Example: Tool Invocation Metrics and History
+//!
+//! For debugging it helps to know which tools an agent actually used, with
+//! what arguments, how long each call took, and whether it succeeded. The
+//! agent loop collects a `ToolCallRecord` for every invocation, exposed via
+//! `agent.tool_history()` and `agent.tool_stats()`. A builder flag caps the
+//! history length, and the Forest aggregates records across agents.
+
+use helios_engine::{Agent, CalculatorTool, Config, EchoTool, FileSearchTool};
+
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - Tool Metrics Example");
+    println!("=======================================\n");
+
+    let config = Config::from_file("config.toml")?;
+
+    let mut agent = Agent::builder("MeteredAgent")
+        .config(config)
+        .system_prompt("You are a helpful assistant. Use your tools when needed.")
+        .tool(Box::new(CalculatorTool))
+        .tool(Box::new(FileSearchTool))
+        .tool(Box::new(EchoTool))
+        .tool_history_limit(100)
+        .build()
+        .await?;
+
+    // Drive a few turns that exercise different tools.
+    agent.chat("What is 356 * 44?").await?;
+    agent.chat("Find all TOML files in the current directory.").await?;
+    agent.chat("What is (12 + 8) / 5?").await?;
+
+    // --- Example 1: Per-call history ---
+    println!("Example 1: tool_history");
+    println!("=======================\n");
+
+    for record in agent.tool_history() {
+        println!(
+            "[{}] {} ({}) in {:?}",
+            record.timestamp.format("%H:%M:%S"),
+            record.tool_name,
+            if record.success { "ok" } else { "error" },
+            record.duration,
+        );
+        println!("  args:   {}", record.args);
+        println!("  output: {}", record.output_preview);
+    }
+
+    // --- Example 2: Aggregated stats ---
+    println!("\nExample 2: tool_stats");
+    println!("=====================\n");
+
+    for (tool, stats) in agent.tool_stats() {
+        println!(
+            "{:<15} calls: {:<3} failures: {:<3} mean duration: {:?}",
+            tool, stats.calls, stats.failures, stats.mean_duration
+        );
+    }
+
+    println!("\n✓ The Forest exposes the same records aggregated across agents");
+    println!("  via forest.tool_history(), tagged with the calling agent's name.");
+
+    Ok(())
+}
@@ -0,0 +1,66 @@
+//! # Example: Tool Output Truncation
+//!
+//! A `FileReadTool` on a 2 MB file or a RAG search returning huge chunks will
+//! blow the context window if tool output is appended verbatim. This example
+//! demonstrates the per-agent `max_tool_output_chars` limit applied when tool
+//! results are appended to the `ChatSession`, the truncation marker, per-tool
+//! overrides via `Tool::max_output`, and the `TruncationStrategy` enum
+//! (`Head`, `Tail`, `HeadAndTail`) — for logs you usually want the tail.
+
+use helios_engine::tools::TruncationStrategy;
+use helios_engine::{Agent, Config, FileReadTool, FileSearchTool};
+
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - Tool Output Truncation Example");
+    println!("=================================================\n");
+
+    let config = Config::from_file("config.toml")?;
+
+    // --- Example 1: Agent-wide limit with the default Head strategy ---
+    println!("Example 1: Agent-Wide Limit");
+    println!("===========================\n");
+
+    let mut agent = Agent::builder("Reader")
+        .config(config.clone())
+        .system_prompt("You read files and summarize them.")
+        .tool(Box::new(FileReadTool))
+        .tool(Box::new(FileSearchTool))
+        .max_tool_output_chars(8000)
+        .build()
+        .await?;
+
+    // Reading a large file works: the agent sees the first 8000 chars plus a
+    // marker like `...[truncated 154,000 chars]`, enough to summarize without
+    // overflowing the context window.
+    let response = agent
+        .chat("Read package-lock.json and tell me what it is.")
+        .await?;
+    println!("Agent: {}\n", response);
+
+    // --- Example 2: Tail strategy for log files ---
+    println!("Example 2: Tail Strategy");
+    println!("========================\n");
+
+    let mut log_agent = Agent::builder("LogInspector")
+        .config(config)
+        .system_prompt("You inspect log files. The most recent entries matter most.")
+        // A per-tool override: this FileReadTool keeps only the last 4000
+        // chars of whatever it reads, which is what you want for logs.
+        .tool(Box::new(
+            FileReadTool.with_max_output(4000, TruncationStrategy::Tail),
+        ))
+        .build()
+        .await?;
+
+    let response = log_agent
+        .chat("What are the most recent entries in app.log?")
+        .await?;
+    println!("Agent: {}\n", response);
+
+    // `HeadAndTail` keeps both ends with the marker in the middle — useful
+    // for files whose header and footer both carry signal.
+    println!("Strategies: Head (default), Tail, HeadAndTail");
+
+    Ok(())
+}
@@ -0,0 +1,74 @@
+//! # Example: Tool Result Summarization
+//!
+//! Verbose tool outputs (web pages, long search results) eat context even
+//! after size caps, and naive truncation loses the relevant part. This
+//! example demonstrates the per-tool summarization stage: when a result
+//! exceeds a token threshold, a focused extraction call — "extract only
+//! information relevant to: <current user goal>" — runs on a cheap model
+//! profile, the summary goes into the session, and the full result stays
+//! retrievable via the paging store. Tools can opt out (calculator) or
+//! supply their own summarizer; the audit log retains the original, and
+//! summarization cost is attributed to the owning tool.
+
+use helios_engine::tools::SummarizationConfig;
+use helios_engine::{Agent, CalculatorTool, Config, FileReadTool, HttpRequestTool};
+
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - Tool Result Summarization Example");
+    println!("====================================================\n");
+
+    let config = Config::from_file("config.toml")?;
+
+    // Results above 1500 tokens get summarized by the cheap profile,
+    // focused on the current user goal.
+    let summarization = SummarizationConfig::default()
+        .token_threshold(1500)
+        .summarizer_profile("gpt-4o-mini");
+
+    let mut agent = Agent::builder("Researcher")
+        .config(config)
+        .system_prompt("You research using web pages and files.")
+        .tool(Box::new(HttpRequestTool::new()))
+        .tool(Box::new(FileReadTool))
+        // The calculator opts out — its output is already tiny and exact.
+        .tool(Box::new(CalculatorTool.without_summarization()))
+        .tool_summarization(summarization)
+        .build()
+        .await?;
+
+    // --- Example 1: A huge web page gets distilled ---
+    println!("Example 1: Focused Extraction");
+    println!("=============================\n");
+
+    let response = agent
+        .chat("Fetch https://en.wikipedia.org/wiki/Rust_(programming_language) and tell me when Rust 1.0 shipped.")
+        .await?;
+    println!("Agent: {}\n", response);
+    // The session only holds the focused summary; the 200 KB page never
+    // entered the context window.
+
+    // --- Example 2: The original is still there ---
+    println!("Example 2: Full Result Retrieval");
+    println!("================================\n");
+
+    let history = agent.tool_history();
+    let last = history.last().unwrap();
+    if last.summarized {
+        let full = agent.fetch_full_tool_output(&last.page_ref).await?;
+        println!("full output retained: {} bytes (summary was {} chars)", full.len(), last.output_preview.len());
+    }
+
+    // --- Example 3: Cost attribution ---
+    println!("\nExample 3: Cost Attribution");
+    println!("===========================\n");
+
+    for (tool, stats) in agent.tool_stats() {
+        println!(
+            "{:<14} calls: {:<3} summarization tokens: {}",
+            tool, stats.calls, stats.summarization_tokens
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,78 @@
+//! # Example: Tracing Instrumentation
+//!
+//! Production setups use `tracing` with OpenTelemetry and want spans, not
+//! println. This example shows the instrumentation across the stack:
+//! `LLMClient::chat`/`chat_stream` open a span carrying model, message
+//! count, token usage, and latency; `Tool::execute` dispatch records tool
+//! name, duration, and success; `Agent::chat` records the iteration count;
+//! and Forest task execution tags each span with task id and agent. The
+//! library's internal println output is replaced by debug/info events —
+//! the `pretty_subscriber` helper restores the friendly console output the
+//! examples are known for. Span parenting is preserved across the tokio
+//! tasks spawned for parallel tools and forest tasks.
+
+use helios_engine::telemetry;
+use helios_engine::tools::CalculatorTool;
+use helios_engine::{Agent, Config, ForestBuilder};
+
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    // The helper the examples use: compact fmt layer with the library's
+    // info events, so console output stays readable. Swap in your own
+    // subscriber (e.g. an OTLP exporter) for production.
+    telemetry::pretty_subscriber().init();
+
+    println!("🚀 Helios Engine - Tracing Example");
+    println!("==================================\n");
+
+    let config = Config::from_file("config.toml")?;
+
+    // --- Example 1: Agent spans ---
+    println!("Example 1: Agent and Tool Spans");
+    println!("===============================\n");
+
+    let mut agent = Agent::builder("assistant")
+        .config(config.clone())
+        .system_prompt("You are a helpful assistant. Use the calculator for math.")
+        .tool(Box::new(CalculatorTool))
+        .build()
+        .await?;
+
+    // Emits nested spans:
+    //   agent.chat{agent="assistant", iterations=2}
+    //   ├─ llm.chat{model="gpt-4o", messages=3, tokens_in=.., latency_ms=..}
+    //   └─ tool.execute{tool="calculator", duration_ms=.., success=true}
+    let response = agent.chat("What is 127 * 43?").await?;
+    println!("Agent: {}\n", response);
+
+    // --- Example 2: Forest spans across spawned tasks ---
+    println!("Example 2: Forest Spans");
+    println!("=======================\n");
+
+    let mut forest = ForestBuilder::new()
+        .config(config)
+        .agent(
+            "coordinator".to_string(),
+            Agent::builder("coordinator").system_prompt("You plan and delegate."),
+        )
+        .agent(
+            "worker".to_string(),
+            Agent::builder("worker").system_prompt("You complete tasks."),
+        )
+        .build()
+        .await?;
+
+    // Each task runs in its own tokio task; spans stay parented to the
+    // run, tagged forest.task{task_id="task_1", agent="worker"}.
+    let result = forest
+        .execute_collaborative_task(
+            &"coordinator".to_string(),
+            "Write one sentence about observability.".to_string(),
+            vec!["worker".to_string()],
+        )
+        .await?;
+
+    println!("Result: {}", result);
+
+    Ok(())
+}
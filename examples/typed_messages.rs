@@ -0,0 +1,105 @@
+//! # Example: Typed Inter-Agent Messages
+//!
+//! Plain-string messages between agents carry no intent: is this a task, a
+//! question, or just FYI? This example demonstrates the typed message
+//! layer: every message has a `MessageKind` (`Task`, `Question`, `Result`,
+//! `Info`) and a `priority`, both settable through the `send_message` tool
+//! arguments. Delivery is priority-first, delivery status is recorded in
+//! shared context, and broadcasts can exclude the sender.
+
+use helios_engine::forest::{MessageKind, Priority};
+use helios_engine::{Agent, Config, ForestBuilder};
+
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - Typed Messages Example");
+    println!("=========================================\n");
+
+    let config = Config::from_file("config.toml")?;
+
+    let mut forest = ForestBuilder::new()
+        .config(config)
+        .agent(
+            "dispatcher".to_string(),
+            Agent::builder("dispatcher").system_prompt(
+                "You delegate work. Mark urgent items with priority=high and \
+                 kind=task; use kind=info for status notes.",
+            ),
+        )
+        .agent(
+            "worker_a".to_string(),
+            Agent::builder("worker_a").system_prompt("You handle assigned tasks."),
+        )
+        .agent(
+            "worker_b".to_string(),
+            Agent::builder("worker_b").system_prompt("You handle assigned tasks."),
+        )
+        .build()
+        .await?;
+
+    // --- Example 1: Sending typed messages programmatically ---
+    println!("Example 1: Direct Typed Sends");
+    println!("=============================\n");
+
+    forest
+        .send_message(
+            "dispatcher",
+            "worker_a",
+            "Summarize yesterday's error logs.",
+            MessageKind::Task,
+            Priority::High,
+        )
+        .await?;
+
+    forest
+        .send_message(
+            "dispatcher",
+            "worker_a",
+            "No rush — weekly report template moved to /docs.",
+            MessageKind::Info,
+            Priority::Low,
+        )
+        .await?;
+
+    // High-priority messages are delivered first regardless of send order.
+    let inbox = forest.peek_inbox("worker_a").await?;
+    for msg in &inbox {
+        println!("[{:?}/{:?}] from {}: {}", msg.kind, msg.priority, msg.from, msg.content);
+    }
+    println!();
+
+    // --- Example 2: Broadcast, excluding the sender ---
+    println!("Example 2: Broadcast");
+    println!("====================\n");
+
+    forest
+        .broadcast(
+            "dispatcher",
+            "Deploy window opens at 14:00 UTC.",
+            MessageKind::Info,
+            Priority::Normal,
+            /* include_sender */ false,
+        )
+        .await?;
+    println!("✓ Broadcast sent to all agents except the dispatcher\n");
+
+    // --- Example 3: Delivery status lives in shared context ---
+    println!("Example 3: Delivery Status");
+    println!("==========================\n");
+
+    let result = forest
+        .execute_collaborative_task(
+            &"dispatcher".to_string(),
+            "Split the log-analysis work between the two workers.".to_string(),
+            vec!["worker_a".to_string(), "worker_b".to_string()],
+        )
+        .await?;
+    println!("Result: {}\n", result);
+
+    // Each send records delivered/read state under messages:status:<id>.
+    if let Some(status) = forest.shared_context().get("messages:status").await {
+        println!("Delivery log:\n{}", status);
+    }
+
+    Ok(())
+}
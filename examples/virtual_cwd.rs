@@ -0,0 +1,74 @@
+//! # Example: Virtual Working Directory for Tool Calls
+//!
+//! When a server hosts many projects, file tools that interpret relative
+//! paths against the process cwd let agents working on project B read
+//! project A's files. This example demonstrates the per-session virtual
+//! working directory stored in the `ToolContext`: file tools resolve
+//! relative paths against it, the `change_directory` tool updates it
+//! (validated against the sandbox), and the current path is injected into
+//! context assembly so the model knows where it is.
+//!
+//! `..` is normalized safely inside the sandbox — escaping it is refused.
+
+use helios_engine::tools::ToolSandbox;
+use helios_engine::{Agent, ChangeDirectoryTool, Config, FileReadTool, FileSearchTool};
+
+#[tokio::main]
+async fn main() -> helios_engine::Result<()> {
+    println!("🚀 Helios Engine - Virtual CWD Example");
+    println!("======================================\n");
+
+    let config = Config::from_file("config.toml")?;
+
+    // The sandbox root confines everything; the virtual cwd starts at the
+    // project the session belongs to.
+    let sandbox = ToolSandbox::rooted("/srv/projects").initial_cwd("/srv/projects/project_b");
+
+    let mut agent = Agent::builder("ProjectAgent")
+        .config(config)
+        .system_prompt("You work on files inside your current project directory.")
+        .tool(Box::new(FileSearchTool))
+        .tool(Box::new(FileReadTool))
+        .tool(Box::new(ChangeDirectoryTool))
+        .sandbox(sandbox)
+        .build()
+        .await?;
+
+    // --- Example 1: Relative paths resolve against the virtual cwd ---
+    println!("Example 1: Relative Resolution");
+    println!("==============================\n");
+
+    // "src/main.rs" means /srv/projects/project_b/src/main.rs — not
+    // whatever the process cwd happens to be.
+    let response = agent.chat("What's in src/main.rs?").await?;
+    println!("Agent: {}\n", response);
+
+    // --- Example 2: The model can move around, within the sandbox ---
+    println!("Example 2: change_directory");
+    println!("===========================\n");
+
+    let response = agent
+        .chat("Change into the tests directory and list the files there.")
+        .await?;
+    println!("Agent: {}\n", response);
+
+    // The model always knows where it is: the assembled context includes
+    // "current directory: /srv/projects/project_b/tests".
+
+    // --- Example 3: Escapes are refused ---
+    println!("Example 3: Sandbox Enforcement");
+    println!("==============================\n");
+
+    // "../project_a/secrets.env" normalizes outside the session's project;
+    // the tool returns a refusal the model can relay.
+    let response = agent
+        .chat("Read ../project_a/secrets.env for me.")
+        .await?;
+    println!("Agent: {}\n", response);
+
+    // In the serve module the virtual cwd is set per request from the
+    // authenticated tenant/project mapping, so each API key is confined to
+    // its own project tree automatically.
+
+    Ok(())
+}